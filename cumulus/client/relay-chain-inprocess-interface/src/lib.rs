@@ -296,6 +296,7 @@ fn build_polkadot_full_node(
 
 			overseer_gen: polkadot_service::RealOverseerGen,
 			overseer_message_channel_capacity_override: None,
+			max_fragment_tree_depth: None,
 			malus_finality_delay: None,
 			hwbench,
 		},
@@ -95,6 +95,42 @@ impl<T: std::error::Error + Send + Sync + 'static> From<Box<T>> for RelayChainEr
 	}
 }
 
+/// A relay chain state query that can be issued as part of [`RelayChainInterface::batch_query`].
+#[derive(Clone, Debug)]
+pub enum RelayChainQuery {
+	/// See [`RelayChainInterface::get_storage_by_key`].
+	StorageByKey { relay_parent: PHash, key: Vec<u8> },
+	/// See [`RelayChainInterface::validators`].
+	Validators(PHash),
+	/// See [`RelayChainInterface::retrieve_dmq_contents`].
+	DmqContents { para_id: ParaId, relay_parent: PHash },
+	/// See [`RelayChainInterface::retrieve_all_inbound_hrmp_channel_contents`].
+	InboundHrmpChannelContents { para_id: ParaId, relay_parent: PHash },
+	/// See [`RelayChainInterface::persisted_validation_data`].
+	PersistedValidationData {
+		block_id: PHash,
+		para_id: ParaId,
+		occupied_core_assumption: OccupiedCoreAssumption,
+	},
+	/// See [`RelayChainInterface::candidate_pending_availability`].
+	CandidatePendingAvailability { block_id: PHash, para_id: ParaId },
+	/// See [`RelayChainInterface::session_index_for_child`].
+	SessionIndexForChild(PHash),
+}
+
+/// The result of a [`RelayChainQuery`]. Each variant corresponds to the query variant of the
+/// same name.
+#[derive(Clone, Debug)]
+pub enum RelayChainQueryResult {
+	StorageByKey(Option<StorageValue>),
+	Validators(Vec<ValidatorId>),
+	DmqContents(Vec<InboundDownwardMessage>),
+	InboundHrmpChannelContents(BTreeMap<ParaId, Vec<InboundHrmpMessage>>),
+	PersistedValidationData(Option<PersistedValidationData>),
+	CandidatePendingAvailability(Option<CommittedCandidateReceipt>),
+	SessionIndexForChild(SessionIndex),
+}
+
 /// Trait that provides all necessary methods for interaction between collator and relay chain.
 #[async_trait]
 pub trait RelayChainInterface: Send + Sync {
@@ -194,6 +230,55 @@ pub trait RelayChainInterface: Send + Sync {
 		relay_parent: PHash,
 		relevant_keys: &Vec<Vec<u8>>,
 	) -> RelayChainResult<StorageProof>;
+
+	/// Issue multiple state queries at once.
+	///
+	/// The results are returned in the same order as `queries`. The default implementation
+	/// issues the queries one after another. Implementations that talk to a remote node should
+	/// override it to save round-trips.
+	async fn batch_query(
+		&self,
+		queries: Vec<RelayChainQuery>,
+	) -> RelayChainResult<Vec<RelayChainQueryResult>> {
+		let mut results = Vec::with_capacity(queries.len());
+		for query in queries {
+			let result = match query {
+				RelayChainQuery::StorageByKey { relay_parent, key } =>
+					RelayChainQueryResult::StorageByKey(
+						self.get_storage_by_key(relay_parent, &key).await?,
+					),
+				RelayChainQuery::Validators(block_id) =>
+					RelayChainQueryResult::Validators(self.validators(block_id).await?),
+				RelayChainQuery::DmqContents { para_id, relay_parent } =>
+					RelayChainQueryResult::DmqContents(
+						self.retrieve_dmq_contents(para_id, relay_parent).await?,
+					),
+				RelayChainQuery::InboundHrmpChannelContents { para_id, relay_parent } =>
+					RelayChainQueryResult::InboundHrmpChannelContents(
+						self.retrieve_all_inbound_hrmp_channel_contents(para_id, relay_parent)
+							.await?,
+					),
+				RelayChainQuery::PersistedValidationData {
+					block_id,
+					para_id,
+					occupied_core_assumption,
+				} => RelayChainQueryResult::PersistedValidationData(
+					self.persisted_validation_data(block_id, para_id, occupied_core_assumption)
+						.await?,
+				),
+				RelayChainQuery::CandidatePendingAvailability { block_id, para_id } =>
+					RelayChainQueryResult::CandidatePendingAvailability(
+						self.candidate_pending_availability(block_id, para_id).await?,
+					),
+				RelayChainQuery::SessionIndexForChild(block_id) =>
+					RelayChainQueryResult::SessionIndexForChild(
+						self.session_index_for_child(block_id).await?,
+					),
+			};
+			results.push(result);
+		}
+		Ok(results)
+	}
 }
 
 #[async_trait]
@@ -301,4 +386,180 @@ where
 	async fn header(&self, block_id: BlockId) -> RelayChainResult<Option<PHeader>> {
 		(**self).header(block_id).await
 	}
+
+	async fn batch_query(
+		&self,
+		queries: Vec<RelayChainQuery>,
+	) -> RelayChainResult<Vec<RelayChainQueryResult>> {
+		(**self).batch_query(queries).await
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::sync::Mutex;
+
+	/// Answers the queries used by the tests and records the order in which they were issued.
+	#[derive(Default)]
+	struct RecordingRelayChainInterface {
+		calls: Mutex<Vec<&'static str>>,
+	}
+
+	impl RecordingRelayChainInterface {
+		fn record(&self, call: &'static str) {
+			self.calls.lock().unwrap().push(call);
+		}
+	}
+
+	#[async_trait]
+	impl RelayChainInterface for RecordingRelayChainInterface {
+		async fn get_storage_by_key(
+			&self,
+			_: PHash,
+			key: &[u8],
+		) -> RelayChainResult<Option<StorageValue>> {
+			self.record("get_storage_by_key");
+			if key.is_empty() {
+				return Err(RelayChainError::GenericError("empty key".into()))
+			}
+			Ok(Some(key.to_vec()))
+		}
+
+		async fn validators(&self, _: PHash) -> RelayChainResult<Vec<ValidatorId>> {
+			self.record("validators");
+			Ok(Vec::new())
+		}
+
+		async fn best_block_hash(&self) -> RelayChainResult<PHash> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn header(&self, _: BlockId) -> RelayChainResult<Option<PHeader>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn finalized_block_hash(&self) -> RelayChainResult<PHash> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn retrieve_dmq_contents(
+			&self,
+			_: ParaId,
+			_: PHash,
+		) -> RelayChainResult<Vec<InboundDownwardMessage>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn retrieve_all_inbound_hrmp_channel_contents(
+			&self,
+			_: ParaId,
+			_: PHash,
+		) -> RelayChainResult<BTreeMap<ParaId, Vec<InboundHrmpMessage>>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn persisted_validation_data(
+			&self,
+			_: PHash,
+			_: ParaId,
+			_: OccupiedCoreAssumption,
+		) -> RelayChainResult<Option<PersistedValidationData>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn candidate_pending_availability(
+			&self,
+			_: PHash,
+			_: ParaId,
+		) -> RelayChainResult<Option<CommittedCandidateReceipt>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn session_index_for_child(&self, block_id: PHash) -> RelayChainResult<SessionIndex> {
+			self.record("session_index_for_child");
+			Ok(block_id.to_low_u64_be() as SessionIndex)
+		}
+
+		async fn import_notification_stream(
+			&self,
+		) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn new_best_notification_stream(
+			&self,
+		) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn wait_for_block(&self, _: PHash) -> RelayChainResult<()> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn finality_notification_stream(
+			&self,
+		) -> RelayChainResult<Pin<Box<dyn Stream<Item = PHeader> + Send>>> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn is_major_syncing(&self) -> RelayChainResult<bool> {
+			unimplemented!("Not needed for test")
+		}
+
+		fn overseer_handle(&self) -> RelayChainResult<OverseerHandle> {
+			unimplemented!("Not needed for test")
+		}
+
+		async fn prove_read(&self, _: PHash, _: &Vec<Vec<u8>>) -> RelayChainResult<StorageProof> {
+			unimplemented!("Not needed for test")
+		}
+	}
+
+	#[test]
+	fn default_batch_query_keeps_order() {
+		let interface = Arc::new(RecordingRelayChainInterface::default());
+		let queries = vec![
+			RelayChainQuery::SessionIndexForChild(PHash::from_low_u64_be(7)),
+			RelayChainQuery::StorageByKey { relay_parent: PHash::zero(), key: vec![1] },
+			RelayChainQuery::Validators(PHash::zero()),
+			RelayChainQuery::StorageByKey { relay_parent: PHash::zero(), key: vec![2] },
+		];
+
+		let results = futures::executor::block_on(interface.batch_query(queries)).unwrap();
+
+		assert_eq!(
+			*interface.calls.lock().unwrap(),
+			vec![
+				"session_index_for_child",
+				"get_storage_by_key",
+				"validators",
+				"get_storage_by_key"
+			],
+		);
+		assert_eq!(results.len(), 4);
+		assert!(matches!(results[0], RelayChainQueryResult::SessionIndexForChild(7)));
+		assert!(
+			matches!(&results[1], RelayChainQueryResult::StorageByKey(Some(value)) if *value == vec![1])
+		);
+		assert!(matches!(&results[2], RelayChainQueryResult::Validators(v) if v.is_empty()));
+		assert!(
+			matches!(&results[3], RelayChainQueryResult::StorageByKey(Some(value)) if *value == vec![2])
+		);
+	}
+
+	#[test]
+	fn default_batch_query_stops_at_first_error() {
+		let interface = RecordingRelayChainInterface::default();
+		let queries = vec![
+			RelayChainQuery::StorageByKey { relay_parent: PHash::zero(), key: vec![1] },
+			RelayChainQuery::StorageByKey { relay_parent: PHash::zero(), key: Vec::new() },
+			RelayChainQuery::Validators(PHash::zero()),
+		];
+
+		let result = futures::executor::block_on(interface.batch_query(queries));
+
+		assert!(matches!(result, Err(RelayChainError::GenericError(_))));
+		assert_eq!(*interface.calls.lock().unwrap(), vec!["get_storage_by_key"; 2]);
+	}
 }
@@ -24,7 +24,8 @@ use cumulus_primitives_core::{
 	InboundDownwardMessage, ParaId, PersistedValidationData,
 };
 use cumulus_relay_chain_interface::{
-	PHeader, RelayChainError, RelayChainInterface, RelayChainResult,
+	PHeader, RelayChainError, RelayChainInterface, RelayChainQuery, RelayChainQueryResult,
+	RelayChainResult,
 };
 use futures::{FutureExt, Stream, StreamExt};
 use polkadot_overseer::Handle;
@@ -188,6 +189,14 @@ impl RelayChainInterface for RelayChainRpcInterface {
 			})
 	}
 
+	/// Issue all `queries` in a single JSON-RPC batch, resulting in only one round-trip.
+	async fn batch_query(
+		&self,
+		queries: Vec<RelayChainQuery>,
+	) -> RelayChainResult<Vec<RelayChainQueryResult>> {
+		self.rpc_client.batch_query(queries).await
+	}
+
 	/// Wait for a given relay chain block
 	///
 	/// The hash of the block to wait for is passed. We wait for the block to arrive or return after
@@ -268,7 +268,39 @@ impl LightClientRpcWorker {
 									"Recipient no longer interested in request result"
 								);
 							};
-						});
+						}.boxed());
+					},
+					Some(RpcDispatcherMessage::BatchRequest(requests, response_sender)) => {
+						let closure_client = self.smoldot_client.clone();
+						tracing::debug!(
+							target: LOG_TARGET,
+							len = pending_requests.len(),
+							batch_len = requests.len(),
+							"Batch request"
+						);
+						// The requests are forwarded to smoldot one by one, as it does not
+						// support JSON-RPC batches.
+						pending_requests.push(async move {
+							let response = futures::future::try_join_all(
+								requests.into_iter().map(|(method, params)| {
+									let client = closure_client.clone();
+									async move { client.request(method, params).await }
+								}),
+							)
+							.await;
+							tracing::debug!(
+								target: LOG_TARGET,
+								?response,
+								"Batch response"
+							);
+							if let Err(err) = response_sender.send(response) {
+								tracing::debug!(
+									target: LOG_TARGET,
+									?err,
+									"Recipient no longer interested in batch request result"
+								);
+							};
+						}.boxed());
 					},
 					None => {
 						tracing::error!(target: LOG_TARGET, "RPC client receiver closed. Stopping RPC Worker.");
@@ -26,9 +26,10 @@ use futures::{
 use jsonrpsee::{
 	core::{
 		client::{Client as JsonRpcClient, ClientT, Subscription},
-		params::ArrayParams,
+		params::{ArrayParams, BatchRequestBuilder},
 		Error as JsonRpseeError, JsonValue,
 	},
+	types::error::CallError,
 	ws_client::WsClientBuilder,
 };
 use sc_rpc_api::chain::ChainApiClient;
@@ -219,6 +220,55 @@ impl ClientManager {
 		}
 		.boxed()
 	}
+
+	/// Create a request future that performs a batch of RPC requests and sends the results to the
+	/// caller. In case of a dead websocket connection, it returns the original batch to enable
+	/// retries.
+	fn create_batch_request(
+		&self,
+		requests: Vec<(&'static str, ArrayParams)>,
+		response_sender: OneshotSender<Result<Vec<JsonValue>, JsonRpseeError>>,
+	) -> BoxFuture<'static, Result<(), RpcDispatcherMessage>> {
+		let future_client = self.active_client.clone();
+		async move {
+			let mut batch = BatchRequestBuilder::new();
+			let inserted = requests
+				.iter()
+				.try_for_each(|(method, params)| batch.insert(*method, params.clone()));
+
+			let resp = match inserted {
+				Ok(()) =>
+					future_client.batch_request::<JsonValue>(batch).await.and_then(|responses| {
+						responses
+							.into_iter()
+							.map(|entry| {
+								entry.map_err(|err| {
+									JsonRpseeError::Call(CallError::Custom(err.into_owned()))
+								})
+							})
+							.collect::<Result<Vec<_>, _>>()
+					}),
+				Err(err) => Err(err),
+			};
+
+			// We should only return the original request in case
+			// the websocket connection is dead and requires a restart.
+			// Other errors should be forwarded to the request caller.
+			if let Err(JsonRpseeError::RestartNeeded(_)) = resp {
+				return Err(RpcDispatcherMessage::BatchRequest(requests, response_sender))
+			}
+
+			if let Err(err) = response_sender.send(resp) {
+				tracing::debug!(
+					target: LOG_TARGET,
+					?err,
+					"Recipient no longer interested in batch request result"
+				);
+			}
+			Ok(())
+		}
+		.boxed()
+	}
 }
 
 enum ConnectionStatus {
@@ -254,7 +304,11 @@ impl ReconnectingWebsocketWorker {
 		first_failed_request: Option<RpcDispatcherMessage>,
 	) -> Result<RelayChainSubscriptions, String> {
 		let mut requests_to_retry = Vec::new();
-		if let Some(req @ RpcDispatcherMessage::Request(_, _, _)) = first_failed_request {
+		if let Some(
+			req @ (RpcDispatcherMessage::Request(_, _, _) |
+			RpcDispatcherMessage::BatchRequest(_, _)),
+		) = first_failed_request
+		{
 			requests_to_retry.push(req);
 		}
 
@@ -271,13 +325,20 @@ impl ReconnectingWebsocketWorker {
 		};
 
 		for item in requests_to_retry.into_iter() {
-			if let RpcDispatcherMessage::Request(method, params, response_sender) = item {
-				pending_requests.push(client_manager.create_request(
-					method,
-					params,
-					response_sender,
-				));
-			};
+			match item {
+				RpcDispatcherMessage::Request(method, params, response_sender) => {
+					pending_requests.push(client_manager.create_request(
+						method,
+						params,
+						response_sender,
+					));
+				},
+				RpcDispatcherMessage::BatchRequest(requests, response_sender) => {
+					pending_requests
+						.push(client_manager.create_batch_request(requests, response_sender));
+				},
+				_ => {},
+			}
 		}
 
 		client_manager.get_subscriptions().await.map_err(|e| {
@@ -350,6 +411,9 @@ impl ReconnectingWebsocketWorker {
 					Some(RpcDispatcherMessage::Request(method, params, response_sender)) => {
 						pending_requests.push(client_manager.create_request(method, params, response_sender));
 					},
+					Some(RpcDispatcherMessage::BatchRequest(requests, response_sender)) => {
+						pending_requests.push(client_manager.create_batch_request(requests, response_sender));
+					},
 					None => {
 						tracing::error!(target: LOG_TARGET, "RPC client receiver closed. Stopping RPC Worker.");
 						return;
@@ -40,7 +40,9 @@ use cumulus_primitives_core::{
 	},
 	InboundDownwardMessage, ParaId, PersistedValidationData,
 };
-use cumulus_relay_chain_interface::{RelayChainError, RelayChainResult};
+use cumulus_relay_chain_interface::{
+	RelayChainError, RelayChainQuery, RelayChainQueryResult, RelayChainResult,
+};
 
 use sc_client_api::StorageData;
 use sc_rpc_api::{state::ReadProof, system::Health};
@@ -80,6 +82,15 @@ pub enum RpcDispatcherMessage {
 	/// - [`ArrayParams`] for the parameters to the RPC call
 	/// - [`OneshotSender`] for the return value of the request
 	Request(String, ArrayParams, OneshotSender<Result<JsonValue, JsonRpseeError>>),
+
+	/// Perform multiple RPC requests as a single JSON-RPC batch.
+	/// Contains the following:
+	/// - [`Vec`] of RPC methods and their parameters
+	/// - [`OneshotSender`] for the return values of the requests, in the order they were given
+	BatchRequest(
+		Vec<(&'static str, ArrayParams)>,
+		OneshotSender<Result<Vec<JsonValue>, JsonRpseeError>>,
+	),
 }
 
 /// Entry point to create [`RelayChainRpcClient`] and start a worker that communicates
@@ -141,13 +152,7 @@ impl RelayChainRpcClient {
 		hash: RelayHash,
 		payload: Option<impl Encode>,
 	) -> RelayChainResult<R> {
-		let payload_bytes =
-			payload.map_or(sp_core::Bytes(Vec::new()), |v| sp_core::Bytes(v.encode()));
-		let params = rpc_params! {
-			method_name,
-			payload_bytes,
-			hash
-		};
+		let params = state_call_params(method_name, hash, payload);
 		let res = self
 			.request_tracing::<sp_core::Bytes, _>("state_call", params, |err| {
 				tracing::trace!(
@@ -213,6 +218,60 @@ impl RelayChainRpcClient {
 		})
 	}
 
+	/// Perform multiple RPC requests in a single JSON-RPC batch.
+	async fn batch_request(
+		&self,
+		requests: Vec<(&'static str, ArrayParams)>,
+	) -> Result<Vec<JsonValue>, RelayChainError> {
+		let (tx, rx) = futures::channel::oneshot::channel();
+
+		let message = RpcDispatcherMessage::BatchRequest(requests, tx);
+		self.worker_channel.send(message).await.map_err(|err| {
+			RelayChainError::WorkerCommunicationError(format!(
+				"Unable to send message to RPC worker: {}",
+				err
+			))
+		})?;
+
+		let values = rx.await.map_err(|err| {
+			RelayChainError::WorkerCommunicationError(format!(
+				"Unexpected channel close on RPC worker side: {}",
+				err
+			))
+		})??;
+
+		Ok(values)
+	}
+
+	/// Issue all `queries` as a single JSON-RPC batch.
+	///
+	/// The results are returned in the same order as `queries`.
+	pub async fn batch_query(
+		&self,
+		queries: Vec<RelayChainQuery>,
+	) -> RelayChainResult<Vec<RelayChainQueryResult>> {
+		// `jsonrpsee` rejects empty batches.
+		if queries.is_empty() {
+			return Ok(Vec::new())
+		}
+
+		let requests = queries.iter().map(batch_query_request).collect();
+		let values = self.batch_request(requests).await?;
+		if values.len() != queries.len() {
+			return Err(RelayChainError::GenericError(format!(
+				"Expected {} responses to batch request, got {}",
+				queries.len(),
+				values.len()
+			)))
+		}
+
+		queries
+			.iter()
+			.zip(values)
+			.map(|(query, value)| decode_batch_query_response(query, value))
+			.collect()
+	}
+
 	/// Returns information regarding the current epoch.
 	pub async fn babe_api_current_epoch(&self, at: RelayHash) -> Result<Epoch, RelayChainError> {
 		self.call_remote_runtime_function("BabeApi_current_epoch", at, None::<()>).await
@@ -661,6 +720,97 @@ impl RelayChainRpcClient {
 	}
 }
 
+/// Build the parameters of a `state_call` RPC request.
+fn state_call_params(
+	method_name: &str,
+	hash: RelayHash,
+	payload: Option<impl Encode>,
+) -> ArrayParams {
+	let payload_bytes = payload.map_or(sp_core::Bytes(Vec::new()), |v| sp_core::Bytes(v.encode()));
+	rpc_params! {
+		method_name,
+		payload_bytes,
+		hash
+	}
+}
+
+/// Map a [`RelayChainQuery`] to the RPC method and parameters used to answer it.
+fn batch_query_request(query: &RelayChainQuery) -> (&'static str, ArrayParams) {
+	match query {
+		RelayChainQuery::StorageByKey { relay_parent, key } =>
+			("state_getStorage", rpc_params![StorageKey(key.clone()), Some(*relay_parent)]),
+		RelayChainQuery::Validators(at) =>
+			("state_call", state_call_params("ParachainHost_validators", *at, None::<()>)),
+		RelayChainQuery::DmqContents { para_id, relay_parent } => (
+			"state_call",
+			state_call_params("ParachainHost_dmq_contents", *relay_parent, Some(*para_id)),
+		),
+		RelayChainQuery::InboundHrmpChannelContents { para_id, relay_parent } => (
+			"state_call",
+			state_call_params(
+				"ParachainHost_inbound_hrmp_channels_contents",
+				*relay_parent,
+				Some(*para_id),
+			),
+		),
+		RelayChainQuery::PersistedValidationData {
+			block_id,
+			para_id,
+			occupied_core_assumption,
+		} => (
+			"state_call",
+			state_call_params(
+				"ParachainHost_persisted_validation_data",
+				*block_id,
+				Some((*para_id, *occupied_core_assumption)),
+			),
+		),
+		RelayChainQuery::CandidatePendingAvailability { block_id, para_id } => (
+			"state_call",
+			state_call_params(
+				"ParachainHost_candidate_pending_availability",
+				*block_id,
+				Some(*para_id),
+			),
+		),
+		RelayChainQuery::SessionIndexForChild(at) => (
+			"state_call",
+			state_call_params("ParachainHost_session_index_for_child", *at, None::<()>),
+		),
+	}
+}
+
+/// Decode the raw response to the request built by [`batch_query_request`] for `query`.
+fn decode_batch_query_response(
+	query: &RelayChainQuery,
+	value: JsonValue,
+) -> RelayChainResult<RelayChainQueryResult> {
+	if let RelayChainQuery::StorageByKey { .. } = query {
+		let storage_data: Option<StorageData> = serde_json::from_value(value)
+			.map_err(|_| RelayChainError::RpcCallError("state_getStorage".to_string()))?;
+		return Ok(RelayChainQueryResult::StorageByKey(storage_data.map(|sv| sv.0)))
+	}
+
+	let bytes: sp_core::Bytes = serde_json::from_value(value)
+		.map_err(|_| RelayChainError::RpcCallError("state_call".to_string()))?;
+	let input = &mut &*bytes.0;
+	let result = match query {
+		RelayChainQuery::StorageByKey { .. } => unreachable!("handled above; qed"),
+		RelayChainQuery::Validators(_) => RelayChainQueryResult::Validators(Decode::decode(input)?),
+		RelayChainQuery::DmqContents { .. } =>
+			RelayChainQueryResult::DmqContents(Decode::decode(input)?),
+		RelayChainQuery::InboundHrmpChannelContents { .. } =>
+			RelayChainQueryResult::InboundHrmpChannelContents(Decode::decode(input)?),
+		RelayChainQuery::PersistedValidationData { .. } =>
+			RelayChainQueryResult::PersistedValidationData(Decode::decode(input)?),
+		RelayChainQuery::CandidatePendingAvailability { .. } =>
+			RelayChainQueryResult::CandidatePendingAvailability(Decode::decode(input)?),
+		RelayChainQuery::SessionIndexForChild(_) =>
+			RelayChainQueryResult::SessionIndexForChild(Decode::decode(input)?),
+	};
+	Ok(result)
+}
+
 /// Send `header` through all channels contained in `senders`.
 /// If no one is listening to the sender, it is removed from the vector.
 pub fn distribute_header(header: RelayHeader, senders: &mut Vec<Sender<RelayHeader>>) {
@@ -679,3 +829,139 @@ pub fn distribute_header(header: RelayHeader, senders: &mut Vec<Sender<RelayHead
 				}
 			});
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use futures::executor::block_on;
+	use tokio::sync::mpsc::{channel, Receiver as TokioReceiver};
+
+	fn state_call_response(value: impl Encode) -> JsonValue {
+		serde_json::to_value(sp_core::Bytes(value.encode())).unwrap()
+	}
+
+	/// Answer the next batch request with `responses`, checking that it contains calls to
+	/// `expected_methods` in this order.
+	async fn answer_batch(
+		receiver: &mut TokioReceiver<RpcDispatcherMessage>,
+		expected_methods: Vec<&'static str>,
+		responses: Vec<JsonValue>,
+	) {
+		match receiver.recv().await {
+			Some(RpcDispatcherMessage::BatchRequest(requests, response_sender)) => {
+				let methods: Vec<_> = requests.iter().map(|(method, _)| *method).collect();
+				assert_eq!(methods, expected_methods);
+				response_sender.send(Ok(responses)).unwrap();
+			},
+			other => panic!("Unexpected message: {:?}", other),
+		}
+	}
+
+	#[test]
+	fn batch_query_decodes_results_in_order() {
+		let (sender, mut receiver) = channel(1);
+		let client = RelayChainRpcClient::new(sender);
+		let at = RelayHash::repeat_byte(1);
+		let validator = ValidatorId::from(sp_core::sr25519::Public::from_raw([2; 32]));
+		let queries = vec![
+			RelayChainQuery::SessionIndexForChild(at),
+			RelayChainQuery::StorageByKey { relay_parent: at, key: vec![1] },
+			RelayChainQuery::Validators(at),
+			RelayChainQuery::StorageByKey { relay_parent: at, key: vec![2] },
+			RelayChainQuery::PersistedValidationData {
+				block_id: at,
+				para_id: ParaId::from(100),
+				occupied_core_assumption: OccupiedCoreAssumption::Included,
+			},
+		];
+		let responses = vec![
+			state_call_response(7 as SessionIndex),
+			serde_json::to_value(Some(StorageData(vec![3, 4]))).unwrap(),
+			state_call_response(vec![validator.clone()]),
+			JsonValue::Null,
+			state_call_response(None::<PersistedValidationData>),
+		];
+
+		let (results, ()) = block_on(futures::future::join(
+			client.batch_query(queries),
+			answer_batch(
+				&mut receiver,
+				vec![
+					"state_call",
+					"state_getStorage",
+					"state_call",
+					"state_getStorage",
+					"state_call",
+				],
+				responses,
+			),
+		));
+		let results = results.unwrap();
+
+		assert_eq!(results.len(), 5);
+		assert!(matches!(results[0], RelayChainQueryResult::SessionIndexForChild(7)));
+		assert!(
+			matches!(&results[1], RelayChainQueryResult::StorageByKey(Some(value)) if *value == vec![3, 4])
+		);
+		assert!(
+			matches!(&results[2], RelayChainQueryResult::Validators(validators) if *validators == vec![validator])
+		);
+		assert!(matches!(results[3], RelayChainQueryResult::StorageByKey(None)));
+		assert!(matches!(results[4], RelayChainQueryResult::PersistedValidationData(None)));
+	}
+
+	#[test]
+	fn batch_query_rejects_undecodable_results() {
+		let (sender, mut receiver) = channel(1);
+		let client = RelayChainRpcClient::new(sender);
+		let at = RelayHash::repeat_byte(1);
+
+		// A `state_call` result that is too short for a `SessionIndex`.
+		let (result, ()) = block_on(futures::future::join(
+			client.batch_query(vec![RelayChainQuery::SessionIndexForChild(at)]),
+			answer_batch(&mut receiver, vec!["state_call"], vec![state_call_response(1u8)]),
+		));
+		assert!(matches!(result, Err(RelayChainError::DeserializationError(_))));
+
+		// A `state_getStorage` result that is not hex encoded.
+		let (result, ()) = block_on(futures::future::join(
+			client.batch_query(vec![RelayChainQuery::StorageByKey {
+				relay_parent: at,
+				key: vec![1],
+			}]),
+			answer_batch(&mut receiver, vec!["state_getStorage"], vec![JsonValue::from(1)]),
+		));
+		assert!(
+			matches!(result, Err(RelayChainError::RpcCallError(method)) if method == "state_getStorage")
+		);
+	}
+
+	#[test]
+	fn batch_query_rejects_wrong_number_of_results() {
+		let (sender, mut receiver) = channel(1);
+		let client = RelayChainRpcClient::new(sender);
+		let at = RelayHash::repeat_byte(1);
+
+		let (result, ()) = block_on(futures::future::join(
+			client.batch_query(vec![
+				RelayChainQuery::SessionIndexForChild(at),
+				RelayChainQuery::SessionIndexForChild(at),
+			]),
+			answer_batch(
+				&mut receiver,
+				vec!["state_call", "state_call"],
+				vec![state_call_response(7 as SessionIndex)],
+			),
+		));
+		assert!(matches!(result, Err(RelayChainError::GenericError(_))));
+	}
+
+	#[test]
+	fn empty_batch_query_sends_no_request() {
+		let (sender, mut receiver) = channel(1);
+		let client = RelayChainRpcClient::new(sender);
+
+		assert!(block_on(client.batch_query(Vec::new())).unwrap().is_empty());
+		assert!(receiver.try_recv().is_err());
+	}
+}
@@ -772,4 +772,17 @@ impl<T: frame_system::Config> pallet_nfts::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2954).saturating_mul(n.into()))
 	}
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 10]`.
+	///
+	/// Every item is charged a whole `mint`, including all of its storage accesses, on top of the
+	/// deposit reserved from the sender. This is an upper bound until the `bulk_mint` benchmark is
+	/// run for this runtime.
+	fn bulk_mint(n: u32, ) -> Weight {
+		Self::mint().saturating_mul(n.into())
+			.saturating_add(Weight::from_parts(0, 2603))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -45,6 +45,8 @@ parameter_types! {
 impl Config for Runtime {
 	type Time = Timestamp;
 	type Randomness = RandomnessCollectiveFlip;
+	/// Parachains don't run BABE, so `vrf_random` is unavailable to contracts.
+	type BabeDataGetter = ();
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -131,6 +131,13 @@ pub struct RunCmd {
 	#[arg(long)]
 	pub overseer_channel_capacity_override: Option<usize>,
 
+	/// Cap the depth of prospective parachains fragment trees at the given value, overriding
+	/// the maximum candidate depth configured by the runtime when lower.
+	///
+	/// **Dangerous!** Do not touch unless explicitly adviced to.
+	#[arg(long)]
+	pub max_fragment_tree_depth: Option<usize>,
+
 	/// Path to the directory where auxiliary worker binaries reside. If not specified, the main
 	/// binary's directory is searched first, then `/usr/lib/polkadot` is searched. TESTING ONLY:
 	/// if the path points to an executable rather then directory, that executable is used both as
@@ -314,6 +314,7 @@ where
 				overseer_message_channel_capacity_override: cli
 					.run
 					.overseer_channel_capacity_override,
+				max_fragment_tree_depth: cli.run.max_fragment_tree_depth,
 				malus_finality_delay: maybe_malus_finality_delay,
 				hwbench,
 			},
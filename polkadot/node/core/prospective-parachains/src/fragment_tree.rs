@@ -421,6 +421,11 @@ impl Scope {
 	pub fn base_constraints(&self) -> &Constraints {
 		&self.base_constraints
 	}
+
+	/// Get the maximum depth of candidates allowed by the scope.
+	pub fn max_depth(&self) -> usize {
+		self.max_depth
+	}
 }
 
 /// We use indices into a flat vector to refer to nodes in the tree.
@@ -506,6 +511,14 @@ impl FragmentTree {
 		&self.scope
 	}
 
+	/// Get the length of the longest fragment chain in the tree, i.e. the number of
+	/// candidates on the longest path from the root. Returns 0 for an empty tree.
+	///
+	/// This is bounded by `scope.max_depth() + 1`.
+	pub fn longest_chain_len(&self) -> usize {
+		self.nodes.iter().map(|node| node.depth + 1).max().unwrap_or(0)
+	}
+
 	// Inserts a node and updates child references in a non-root parent.
 	fn insert_node(&mut self, node: FragmentNode) {
 		let pointer = NodePointer::Storage(self.nodes.len());
@@ -1159,6 +1172,34 @@ mod tests {
 		assert_eq!(scope.ancestors_by_hash.len(), 2);
 	}
 
+	#[test]
+	fn empty_tree_has_no_chain() {
+		let storage = CandidateStorage::new();
+		let para_id = ParaId::from(5u32);
+		let relay_parent = RelayChainBlockInfo {
+			number: 0,
+			hash: Hash::repeat_byte(1),
+			storage_root: Hash::repeat_byte(69),
+		};
+
+		let max_depth = 4;
+		let base_constraints = make_constraints(0, vec![0], vec![0x0a].into());
+		let scope = Scope::with_ancestors(
+			para_id,
+			relay_parent,
+			base_constraints,
+			Vec::new(),
+			max_depth,
+			vec![],
+		)
+		.unwrap();
+		let tree = FragmentTree::populate(scope, &storage);
+
+		assert_eq!(tree.candidates().count(), 0);
+		assert_eq!(tree.longest_chain_len(), 0);
+		assert_eq!(tree.scope().max_depth(), max_depth);
+	}
+
 	#[test]
 	fn storage_add_candidate() {
 		let mut storage = CandidateStorage::new();
@@ -1282,6 +1323,8 @@ mod tests {
 		assert_eq!(tree.nodes[1].parent, NodePointer::Storage(0));
 		assert_eq!(tree.nodes[1].candidate_hash, candidate_b_hash);
 		assert_eq!(tree.nodes[1].depth, 1);
+
+		assert_eq!(tree.longest_chain_len(), 2);
 	}
 
 	#[test]
@@ -1347,6 +1390,7 @@ mod tests {
 		)
 		.unwrap();
 		let mut tree = FragmentTree::populate(scope, &storage);
+		assert_eq!(tree.longest_chain_len(), 2);
 
 		storage.add_candidate(candidate_a2, pvd_a2).unwrap();
 		tree.add_and_populate(candidate_a2_hash, &storage);
@@ -1356,6 +1400,9 @@ mod tests {
 		assert_eq!(tree.nodes[0].parent, NodePointer::Root);
 		assert_eq!(tree.nodes[1].parent, NodePointer::Root);
 		assert_eq!(tree.nodes[2].parent, NodePointer::Storage(0));
+
+		// A sibling of the root candidate doesn't make the tree any deeper.
+		assert_eq!(tree.longest_chain_len(), 2);
 	}
 
 	#[test]
@@ -1412,6 +1459,7 @@ mod tests {
 
 		assert_eq!(tree.nodes[0].parent, NodePointer::Root);
 		assert_eq!(tree.nodes[1].parent, NodePointer::Root);
+		assert_eq!(tree.longest_chain_len(), 1);
 	}
 
 	#[test]
@@ -1460,6 +1508,7 @@ mod tests {
 		)
 		.unwrap();
 		let mut tree = FragmentTree::populate(scope, &storage);
+		assert_eq!(tree.longest_chain_len(), 1);
 
 		storage.add_candidate(candidate_b, pvd_b).unwrap();
 		tree.add_and_populate(candidate_b_hash, &storage);
@@ -1468,6 +1517,7 @@ mod tests {
 
 		assert_eq!(tree.nodes[0].parent, NodePointer::Root);
 		assert_eq!(tree.nodes[1].parent, NodePointer::Storage(0));
+		assert_eq!(tree.longest_chain_len(), 2);
 	}
 
 	#[test]
@@ -1511,6 +1561,7 @@ mod tests {
 		let candidates: Vec<_> = tree.candidates().collect();
 		assert_eq!(candidates.len(), 1);
 		assert_eq!(tree.nodes.len(), max_depth + 1);
+		assert_eq!(tree.longest_chain_len(), max_depth + 1);
 
 		assert_eq!(tree.nodes[0].parent, NodePointer::Root);
 		assert_eq!(tree.nodes[1].parent, NodePointer::Storage(0));
@@ -1590,6 +1641,7 @@ mod tests {
 		assert_eq!(tree.nodes[2].candidate_hash, candidate_a_hash);
 		assert_eq!(tree.nodes[3].candidate_hash, candidate_b_hash);
 		assert_eq!(tree.nodes[4].candidate_hash, candidate_a_hash);
+		assert_eq!(tree.longest_chain_len(), max_depth + 1);
 	}
 
 	#[test]
@@ -78,11 +78,18 @@ struct View {
 	// Active or recent relay-chain blocks by block hash.
 	active_leaves: HashMap<Hash, RelayBlockViewData>,
 	candidate_storage: HashMap<ParaId, CandidateStorage>,
+	// Optional local cap on the depth of fragment trees, overriding the runtime's
+	// `max_candidate_depth` when lower.
+	max_fragment_tree_depth: Option<usize>,
 }
 
 impl View {
 	fn new() -> Self {
-		View { active_leaves: HashMap::new(), candidate_storage: HashMap::new() }
+		View {
+			active_leaves: HashMap::new(),
+			candidate_storage: HashMap::new(),
+			max_fragment_tree_depth: None,
+		}
 	}
 }
 
@@ -90,12 +97,20 @@ impl View {
 #[derive(Default)]
 pub struct ProspectiveParachainsSubsystem {
 	metrics: Metrics,
+	max_fragment_tree_depth: Option<usize>,
 }
 
 impl ProspectiveParachainsSubsystem {
 	/// Create a new instance of the `ProspectiveParachainsSubsystem`.
 	pub fn new(metrics: Metrics) -> Self {
-		Self { metrics }
+		Self { metrics, max_fragment_tree_depth: None }
+	}
+
+	/// Cap the depth of fragment trees at the given value, regardless of the
+	/// `max_candidate_depth` set by the runtime. `None` leaves the runtime value untouched.
+	pub fn with_max_fragment_tree_depth(mut self, max_fragment_tree_depth: Option<usize>) -> Self {
+		self.max_fragment_tree_depth = max_fragment_tree_depth;
+		self
 	}
 }
 
@@ -106,7 +121,7 @@ where
 {
 	fn start(self, ctx: Context) -> SpawnedSubsystem {
 		SpawnedSubsystem {
-			future: run(ctx, self.metrics)
+			future: run(ctx, self.metrics, self.max_fragment_tree_depth)
 				.map_err(|e| SubsystemError::with_origin("prospective-parachains", e))
 				.boxed(),
 			name: "prospective-parachains-subsystem",
@@ -115,8 +130,12 @@ where
 }
 
 #[overseer::contextbounds(ProspectiveParachains, prefix = self::overseer)]
-async fn run<Context>(mut ctx: Context, metrics: Metrics) -> FatalResult<()> {
-	let mut view = View::new();
+async fn run<Context>(
+	mut ctx: Context,
+	metrics: Metrics,
+	max_fragment_tree_depth: Option<usize>,
+) -> FatalResult<()> {
+	let mut view = View { max_fragment_tree_depth, ..View::new() };
 	loop {
 		crate::error::log_error(
 			run_iteration(&mut ctx, &mut view, &metrics).await,
@@ -140,7 +159,7 @@ async fn run_iteration<Context>(
 			FromOrchestra::Signal(OverseerSignal::BlockFinalized(..)) => {},
 			FromOrchestra::Communication { msg } => match msg {
 				ProspectiveParachainsMessage::IntroduceCandidate(request, tx) =>
-					handle_candidate_introduced(&mut *ctx, view, request, tx).await?,
+					handle_candidate_introduced(&mut *ctx, view, request, tx, metrics).await?,
 				ProspectiveParachainsMessage::CandidateSeconded(para, candidate_hash) =>
 					handle_candidate_seconded(view, para, candidate_hash),
 				ProspectiveParachainsMessage::CandidateBacked(para, candidate_hash) =>
@@ -188,7 +207,8 @@ async fn handle_active_leaves_update<Context>(
 			.await
 			.map_err(JfyiError::Runtime)?;
 
-		let ProspectiveParachainsMode::Enabled { max_candidate_depth, allowed_ancestry_len } = mode
+		let ProspectiveParachainsMode::Enabled { mut max_candidate_depth, allowed_ancestry_len } =
+			mode
 		else {
 			gum::trace!(
 				target: LOG_TARGET,
@@ -200,6 +220,10 @@ async fn handle_active_leaves_update<Context>(
 			return Ok(())
 		};
 
+		if let Some(cap) = view.max_fragment_tree_depth {
+			max_candidate_depth = max_candidate_depth.min(cap);
+		}
+
 		let mut pending_availability = HashSet::new();
 		let scheduled_paras =
 			fetch_upcoming_paras(&mut *ctx, hash, &mut pending_availability).await?;
@@ -290,6 +314,7 @@ async fn handle_active_leaves_update<Context>(
 			.expect("ancestors are provided in reverse order and correctly; qed");
 
 			let tree = FragmentTree::populate(scope, &*candidate_storage);
+			observe_fragment_tree_depth(&tree, para, hash, metrics);
 
 			fragment_trees.insert(para, tree);
 		}
@@ -306,6 +331,30 @@ async fn handle_active_leaves_update<Context>(
 	Ok(())
 }
 
+fn observe_fragment_tree_depth(
+	tree: &FragmentTree,
+	para: ParaId,
+	relay_parent: Hash,
+	metrics: &Metrics,
+) {
+	let depth = tree.longest_chain_len();
+	metrics.observe_fragment_tree_depth(depth);
+
+	// Deep trees are expected whenever collators build ahead, so this is only logged for
+	// debugging. The histogram is what should be alerted on.
+	let max_depth = tree.scope().max_depth();
+	if depth > max_depth / 2 {
+		gum::debug!(
+			target: LOG_TARGET,
+			para_id = ?para,
+			?relay_parent,
+			depth,
+			max_depth,
+			"Fragment tree depth exceeds half of the maximum depth",
+		);
+	}
+}
+
 fn prune_view_candidate_storage(view: &mut View, metrics: &Metrics) {
 	metrics.time_prune_view_candidate_storage();
 
@@ -401,6 +450,7 @@ async fn handle_candidate_introduced<Context>(
 	view: &mut View,
 	request: IntroduceCandidateRequest,
 	tx: oneshot::Sender<FragmentTreeMembership>,
+	metrics: &Metrics,
 ) -> JfyiErrorResult<()> {
 	let IntroduceCandidateRequest {
 		candidate_para: para,
@@ -452,6 +502,7 @@ async fn handle_candidate_introduced<Context>(
 	for (relay_parent, leaf_data) in &mut view.active_leaves {
 		if let Some(tree) = leaf_data.fragment_trees.get_mut(&para) {
 			tree.add_and_populate(candidate_hash, &*storage);
+			observe_fragment_tree_depth(tree, para, *relay_parent, metrics);
 			if let Some(depths) = tree.candidate(&candidate_hash) {
 				membership.push((*relay_parent, depths));
 			}
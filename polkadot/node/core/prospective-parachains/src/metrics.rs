@@ -19,6 +19,7 @@ use polkadot_node_subsystem_util::metrics::{self, prometheus};
 #[derive(Clone)]
 pub(crate) struct MetricsInner {
 	pub(crate) prune_view_candidate_storage: prometheus::Histogram,
+	pub(crate) fragment_tree_depth: prometheus::Histogram,
 }
 
 /// Candidate backing metrics.
@@ -34,6 +35,13 @@ impl Metrics {
 			.as_ref()
 			.map(|metrics| metrics.prune_view_candidate_storage.start_timer())
 	}
+
+	/// Record the depth of the deepest fragment chain of a fragment tree.
+	pub fn observe_fragment_tree_depth(&self, depth: usize) {
+		if let Some(metrics) = &self.0 {
+			metrics.fragment_tree_depth.observe(depth as f64);
+		}
+	}
 }
 
 impl metrics::Metrics for Metrics {
@@ -46,6 +54,16 @@ impl metrics::Metrics for Metrics {
 				))?,
 				registry,
 			)?,
+			fragment_tree_depth: prometheus::register(
+				prometheus::Histogram::with_opts(
+					prometheus::HistogramOpts::new(
+						"polkadot_parachain_prospective_parachains_fragment_tree_depth",
+						"Depth of the deepest fragment chain in a fragment tree",
+					)
+					.buckets(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 8.0, 10.0, 16.0, 32.0]),
+				)?,
+				registry,
+			)?,
 		};
 		Ok(Metrics(Some(metrics)))
 	}
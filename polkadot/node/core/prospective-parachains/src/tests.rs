@@ -94,12 +94,18 @@ fn get_parent_hash(hash: Hash) -> Hash {
 
 fn test_harness<T: Future<Output = VirtualOverseer>>(
 	test: impl FnOnce(VirtualOverseer) -> T,
+) -> View {
+	test_harness_with_view(View::new(), test)
+}
+
+fn test_harness_with_view<T: Future<Output = VirtualOverseer>>(
+	mut view: View,
+	test: impl FnOnce(VirtualOverseer) -> T,
 ) -> View {
 	let pool = sp_core::testing::TaskExecutor::new();
 
 	let (mut context, virtual_overseer) = test_helpers::make_subsystem_context(pool.clone());
 
-	let mut view = View::new();
 	let subsystem = async move {
 		loop {
 			match run_iteration(&mut context, &mut view, &Metrics(None)).await {
@@ -974,6 +980,75 @@ fn check_backable_query() {
 	assert_eq!(view.candidate_storage.get(&2.into()).unwrap().len(), (0, 0));
 }
 
+// A local depth cap below the runtime's `max_candidate_depth` limits the fragment trees.
+#[test]
+fn max_fragment_tree_depth_caps_tree_depth() {
+	let test_state = TestState::default();
+	let view = View { max_fragment_tree_depth: Some(1), ..View::new() };
+	let view = test_harness_with_view(view, |mut virtual_overseer| async move {
+		// Leaf A
+		let leaf_a = TestLeaf {
+			number: 100,
+			hash: Hash::from_low_u64_be(130),
+			para_data: vec![
+				(1.into(), PerParaData::new(97, HeadData(vec![1, 2, 3]))),
+				(2.into(), PerParaData::new(100, HeadData(vec![2, 3, 4]))),
+			],
+		};
+
+		// Activate leaves.
+		activate_leaf(&mut virtual_overseer, &leaf_a, &test_state).await;
+
+		// A chain of three candidates: A <- B <- C.
+		let (candidate_a, pvd_a) = make_candidate(
+			leaf_a.hash,
+			leaf_a.number,
+			1.into(),
+			HeadData(vec![1, 2, 3]),
+			HeadData(vec![1]),
+			test_state.validation_code_hash,
+		);
+		let candidate_hash_a = candidate_a.hash();
+		let response_a = vec![(leaf_a.hash, vec![0])];
+		let (candidate_b, pvd_b) = make_candidate(
+			leaf_a.hash,
+			leaf_a.number,
+			1.into(),
+			HeadData(vec![1]),
+			HeadData(vec![2]),
+			test_state.validation_code_hash,
+		);
+		let candidate_hash_b = candidate_b.hash();
+		let response_b = vec![(leaf_a.hash, vec![1])];
+		let (candidate_c, pvd_c) = make_candidate(
+			leaf_a.hash,
+			leaf_a.number,
+			1.into(),
+			HeadData(vec![2]),
+			HeadData(vec![3]),
+			test_state.validation_code_hash,
+		);
+		let candidate_hash_c = candidate_c.hash();
+
+		// Introduce candidates.
+		introduce_candidate(&mut virtual_overseer, candidate_a, pvd_a).await;
+		introduce_candidate(&mut virtual_overseer, candidate_b, pvd_b).await;
+		introduce_candidate(&mut virtual_overseer, candidate_c, pvd_c).await;
+
+		// The runtime allows a depth of 4, but only depths up to 1 fit into the tree.
+		get_membership(&mut virtual_overseer, 1.into(), candidate_hash_a, response_a).await;
+		get_membership(&mut virtual_overseer, 1.into(), candidate_hash_b, response_b).await;
+		get_membership(&mut virtual_overseer, 1.into(), candidate_hash_c, vec![]).await;
+
+		virtual_overseer
+	});
+
+	assert_eq!(view.active_leaves.len(), 1);
+	let tree = &view.active_leaves[&Hash::from_low_u64_be(130)].fragment_trees[&ParaId::from(1)];
+	assert_eq!(tree.scope().max_depth(), 1);
+	assert_eq!(tree.longest_chain_len(), 2);
+}
+
 // Test depth query.
 #[test]
 fn check_hypothetical_frontier_query() {
@@ -56,6 +56,40 @@ where
 	}
 }
 
+/// Applies two interceptors to the same subsystem.
+///
+/// Messages are passed to `A` first. Only if `A` lets a message through, `B` gets to see it.
+#[derive(Clone)]
+pub struct ComposedInterceptor<A, B>(pub A, pub B);
+
+impl<Sender, A, B> MessageInterceptor<Sender> for ComposedInterceptor<A, B>
+where
+	Sender: overseer::SubsystemSender<<A::Message as overseer::AssociateOutgoing>::OutgoingMessages>
+		+ Clone
+		+ 'static,
+	A: MessageInterceptor<Sender>,
+	B: MessageInterceptor<Sender, Message = A::Message>,
+{
+	type Message = A::Message;
+
+	fn intercept_incoming(
+		&self,
+		sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		self.0
+			.intercept_incoming(sender, msg)
+			.and_then(|msg| self.1.intercept_incoming(sender, msg))
+	}
+
+	fn intercept_outgoing(
+		&self,
+		msg: <Self::Message as overseer::AssociateOutgoing>::OutgoingMessages,
+	) -> Option<<Self::Message as overseer::AssociateOutgoing>::OutgoingMessages> {
+		self.0.intercept_outgoing(msg).and_then(|msg| self.1.intercept_outgoing(msg))
+	}
+}
+
 /// A sender with the outgoing messages filtered.
 #[derive(Clone)]
 pub struct InterceptedSender<Sender, Fil> {
@@ -36,6 +36,12 @@ enum NemesisVariant {
 	BackGarbageCandidate(BackGarbageCandidateOptions),
 	/// Delayed disputing of ancestors that are perfectly fine.
 	DisputeAncestor(DisputeAncestorOptions),
+	/// Hold back approval votes for a configurable amount of time.
+	DelayApprovals(DelayApprovalsOptions),
+	/// Drop a configurable fraction of outgoing network messages per message type.
+	DropMessages(DropMessagesOptions),
+	/// Acknowledge but do not store a configurable fraction of availability chunks.
+	DropAvailability(DropAvailabilityOptions),
 }
 
 #[derive(Debug, Parser)]
@@ -80,6 +86,26 @@ impl MalusCli {
 					finality_delay,
 				)?
 			},
+			NemesisVariant::DelayApprovals(opts) => {
+				let DelayApprovalsOptions { delay_ms, cli } = opts;
+
+				polkadot_cli::run_node(cli, DelayApprovals { delay_ms }, finality_delay)?
+			},
+			NemesisVariant::DropMessages(opts) => {
+				let DropMessagesOptions { drop_config, cli } = opts;
+				let drop_config = load_drop_config(&drop_config)?;
+
+				polkadot_cli::run_node(cli, DropMessages { drop_config }, finality_delay)?
+			},
+			NemesisVariant::DropAvailability(opts) => {
+				let DropAvailabilityOptions { drop_availability_rate, cli } = opts;
+
+				polkadot_cli::run_node(
+					cli,
+					DropAvailability { drop_availability_rate },
+					finality_delay,
+				)?
+			},
 		}
 		Ok(())
 	}
@@ -166,6 +192,77 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn delay_ms_works_delay_approvals() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"delay-approvals",
+			"--delay-ms",
+			"1500",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::DelayApprovals(run),
+			..
+		} => {
+			assert!(run.cli.run.base.bob);
+			assert_eq!(run.delay_ms, 1500);
+		});
+	}
+
+	#[test]
+	fn drop_config_works_drop_messages() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"drop-messages",
+			"--drop-config",
+			"drop.json",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::DropMessages(run),
+			..
+		} => {
+			assert!(run.cli.run.base.bob);
+			assert_eq!(run.drop_config, std::path::PathBuf::from("drop.json"));
+		});
+	}
+
+	#[test]
+	fn rate_works_drop_availability() {
+		let cli = MalusCli::try_parse_from(IntoIterator::into_iter([
+			"malus",
+			"drop-availability",
+			"--drop-availability-rate",
+			"0.5",
+			"--bob",
+		]))
+		.unwrap();
+		assert_matches::assert_matches!(cli, MalusCli {
+			variant: NemesisVariant::DropAvailability(run),
+			..
+		} => {
+			assert!(run.cli.run.base.bob);
+			assert_eq!(run.drop_availability_rate, 0.5);
+		});
+	}
+
+	#[test]
+	fn validate_range_for_drop_availability_rate() {
+		for rate in ["1.5", "inf", "NaN"] {
+			assert!(MalusCli::try_parse_from(IntoIterator::into_iter([
+				"malus",
+				"drop-availability",
+				"--drop-availability-rate",
+				rate,
+				"--bob",
+			]))
+			.is_err());
+		}
+	}
+
 	#[test]
 	#[should_panic]
 	fn validate_range_for_percentage() {
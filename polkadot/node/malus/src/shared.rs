@@ -15,6 +15,7 @@
 // along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
 
 use futures::prelude::*;
+use polkadot_node_subsystem_util::metrics::prometheus;
 use sp_core::traits::SpawnNamed;
 
 pub const MALUS: &str = "MALUS";
@@ -47,3 +48,80 @@ where
 		}),
 	);
 }
+
+#[derive(Clone)]
+struct MalusMetricsInner {
+	messages_intercepted: prometheus::CounterVec<prometheus::U64>,
+	messages_modified: prometheus::CounterVec<prometheus::U64>,
+	messages_dropped: prometheus::CounterVec<prometheus::U64>,
+}
+
+/// Metrics shared by all malus variants, labeled by variant and message type.
+///
+/// They are registered in the node's registry and thus exposed on its Prometheus endpoint.
+#[derive(Clone)]
+pub(crate) struct MalusMetrics {
+	variant: &'static str,
+	inner: Option<MalusMetricsInner>,
+}
+
+impl std::fmt::Debug for MalusMetrics {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("MalusMetrics").field("variant", &self.variant).finish()
+	}
+}
+
+impl MalusMetrics {
+	/// Register the metrics of the given `variant`, if a registry is available.
+	pub(crate) fn register(
+		registry: Option<&prometheus::Registry>,
+		variant: &'static str,
+	) -> Result<Self, prometheus::PrometheusError> {
+		let inner = registry
+			.map(|registry| {
+				let counter = |name: &str, description: &str| {
+					prometheus::register(
+						prometheus::CounterVec::new(
+							prometheus::Opts::new(name, description),
+							&["variant", "message"],
+						)?,
+						registry,
+					)
+				};
+				Ok::<_, prometheus::PrometheusError>(MalusMetricsInner {
+					messages_intercepted: counter(
+						"polkadot_malus_messages_intercepted_total",
+						"Number of messages seen by the malus interceptors",
+					)?,
+					messages_modified: counter(
+						"polkadot_malus_messages_modified_total",
+						"Number of messages altered or answered with forged data by malus",
+					)?,
+					messages_dropped: counter(
+						"polkadot_malus_messages_dropped_total",
+						"Number of messages dropped by malus",
+					)?,
+				})
+			})
+			.transpose()?;
+		Ok(Self { variant, inner })
+	}
+
+	pub(crate) fn on_intercepted(&self, message: &str) {
+		if let Some(metrics) = &self.inner {
+			metrics.messages_intercepted.with_label_values(&[self.variant, message]).inc();
+		}
+	}
+
+	pub(crate) fn on_modified(&self, message: &str) {
+		if let Some(metrics) = &self.inner {
+			metrics.messages_modified.with_label_values(&[self.variant, message]).inc();
+		}
+	}
+
+	pub(crate) fn on_dropped(&self, message: &str) {
+		if let Some(metrics) = &self.inner {
+			metrics.messages_dropped.with_label_values(&[self.variant, message]).inc();
+		}
+	}
+}
@@ -138,3 +138,29 @@ fn integrity_test_pass() {
 		)
 	})
 }
+
+#[test]
+fn integrity_test_composed() {
+	launch_harness(|mut overseer| {
+		let sub = DummySubsystem;
+
+		let sub_intercepted = InterceptedSubsystem::new(
+			sub,
+			ComposedInterceptor(PassInterceptor, BlackHoleInterceptor),
+		);
+
+		(
+			async move {
+				let (tx, rx) = futures::channel::oneshot::channel();
+				overseer_send(
+					&mut overseer,
+					AvailabilityStoreMessage::QueryChunk(Default::default(), 0.into(), tx),
+				)
+				.await;
+				let _ = rx.timeout(std::time::Duration::from_millis(100)).await.unwrap();
+				overseer
+			},
+			sub_intercepted,
+		)
+	})
+}
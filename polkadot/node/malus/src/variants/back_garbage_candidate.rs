@@ -33,6 +33,7 @@ use sp_core::traits::SpawnNamed;
 
 use crate::{
 	interceptor::*,
+	shared::MalusMetrics,
 	variants::{FakeCandidateValidation, FakeCandidateValidationError, ReplaceValidationResult},
 };
 
@@ -78,6 +79,7 @@ impl OverseerGen for BackGarbageCandidates {
 			FakeCandidateValidationError::InvalidOutputs,
 			f64::from(self.percentage),
 			SpawnGlue(spawner),
+			MalusMetrics::register(args.registry, "back-garbage-candidate")?,
 		);
 
 		prepared_overseer_builder(args)?
@@ -18,7 +18,7 @@
 //! interceptor is implemented.
 use crate::{
 	interceptor::*,
-	shared::{MALICIOUS_POV, MALUS},
+	shared::{MalusMetrics, MALICIOUS_POV, MALUS},
 };
 
 use polkadot_node_core_candidate_validation::find_validation_data;
@@ -150,6 +150,9 @@ impl Into<InvalidCandidate> for FakeCandidateValidationError {
 	}
 }
 
+/// Message type label used for the metrics of [`ReplaceValidationResult`].
+const VALIDATION_MESSAGE: &str = "candidate-validation";
+
 #[derive(Clone, Debug)]
 /// An interceptor which fakes validation result with a preconfigured result.
 /// Replaces `CandidateValidationSubsystem`.
@@ -158,6 +161,7 @@ pub struct ReplaceValidationResult<Spawner> {
 	fake_validation_error: FakeCandidateValidationError,
 	distribution: Bernoulli,
 	spawner: Spawner,
+	metrics: MalusMetrics,
 }
 
 impl<Spawner> ReplaceValidationResult<Spawner>
@@ -169,10 +173,11 @@ where
 		fake_validation_error: FakeCandidateValidationError,
 		percentage: f64,
 		spawner: Spawner,
+		metrics: MalusMetrics,
 	) -> Self {
 		let distribution = Bernoulli::new(percentage / 100.0)
 			.expect("Invalid probability! Percentage must be in range [0..=100].");
-		Self { fake_validation, fake_validation_error, distribution, spawner }
+		Self { fake_validation, fake_validation_error, distribution, spawner, metrics }
 	}
 
 	/// Creates and sends the validation response for a given candidate. Queries the runtime to
@@ -269,6 +274,10 @@ where
 		subsystem_sender: &mut Sender,
 		msg: FromOrchestra<Self::Message>,
 	) -> Option<FromOrchestra<Self::Message>> {
+		if let FromOrchestra::Communication { .. } = msg {
+			self.metrics.on_intercepted(VALIDATION_MESSAGE);
+		}
+
 		match msg {
 			// Message sent by the approval voting subsystem
 			FromOrchestra::Communication {
@@ -315,6 +324,7 @@ where
 									candidate_receipt.descriptor,
 									sender,
 								);
+								self.metrics.on_modified(VALIDATION_MESSAGE);
 								None
 							},
 							false => {
@@ -359,6 +369,7 @@ where
 								// We're not even checking the candidate, this makes us appear
 								// faster than honest validators.
 								sender.send(Ok(validation_result)).unwrap();
+								self.metrics.on_modified(VALIDATION_MESSAGE);
 								None
 							},
 							false => {
@@ -434,6 +445,7 @@ where
 									subsystem_sender.clone(),
 									response_sender,
 								);
+								self.metrics.on_modified(VALIDATION_MESSAGE);
 								None
 							},
 							// If the `PoV` is malicious, we behave normally with some probability
@@ -466,6 +478,7 @@ where
 								// We're not even checking the candidate, this makes us appear
 								// faster than honest validators.
 								response_sender.send(Ok(validation_result)).unwrap();
+								self.metrics.on_modified(VALIDATION_MESSAGE);
 								None
 							},
 							// With some probability `(1-p)` we behave normally
@@ -0,0 +1,193 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! This variant of Malus behaves honestly, but holds back its own approval votes and the
+//! answers to `ApprovedAncestor` requests for a configurable amount of time. This allows
+//! to test how the network copes with approvals arriving close to the no-show timeout.
+//!
+//! Attention: For usage with `zombienet` only!
+
+use polkadot_cli::{
+	prepared_overseer_builder,
+	service::{
+		AuthorityDiscoveryApi, AuxStore, BabeApi, Block, Error, HeaderBackend, Overseer,
+		OverseerConnector, OverseerGen, OverseerGenArgs, OverseerHandle, ParachainHost,
+		ProvideRuntimeApi,
+	},
+	Cli,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::DefaultSubsystemClient;
+use sp_core::traits::SpawnNamed;
+
+use crate::{
+	interceptor::*,
+	shared::{MalusMetrics, MALUS},
+};
+
+use futures::channel::oneshot;
+use futures_timer::Delay;
+use std::{
+	sync::{Arc, Mutex},
+	time::Duration,
+};
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct DelayApprovalsOptions {
+	/// Determines for how many milliseconds approval votes and approved ancestor responses are
+	/// held back.
+	#[clap(long, ignore_case = true, default_value_t = 6000)]
+	pub delay_ms: u64,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// An interceptor which delays the approval votes issued by the approval voting subsystem as
+/// well as its answers to `ApprovedAncestor` requests.
+#[derive(Clone)]
+struct DelayApprovalVoting<Sender, Spawner> {
+	delay: Duration,
+	spawner: Spawner,
+	/// The sender of the approval voting subsystem, used to send the delayed votes. It is
+	/// captured from the first incoming message, which always arrives before any vote is
+	/// issued.
+	sender: Arc<Mutex<Option<Sender>>>,
+	metrics: MalusMetrics,
+}
+
+impl<Sender, Spawner> DelayApprovalVoting<Sender, Spawner> {
+	fn new(delay: Duration, spawner: Spawner, metrics: MalusMetrics) -> Self {
+		Self { delay, spawner, sender: Arc::new(Mutex::new(None)), metrics }
+	}
+}
+
+impl<Sender, Spawner> MessageInterceptor<Sender> for DelayApprovalVoting<Sender, Spawner>
+where
+	Sender: overseer::ApprovalVotingSenderTrait + Clone + Send + 'static,
+	Spawner: overseer::gen::Spawner + Clone + 'static,
+{
+	type Message = ApprovalVotingMessage;
+
+	fn intercept_incoming(
+		&self,
+		subsystem_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		self.sender
+			.lock()
+			.expect("poisoned lock")
+			.get_or_insert_with(|| subsystem_sender.clone());
+		if let FromOrchestra::Communication { .. } = msg {
+			self.metrics.on_intercepted("approval-voting");
+		}
+
+		match msg {
+			FromOrchestra::Communication {
+				msg: ApprovalVotingMessage::ApprovedAncestor(hash, number, response_sender),
+			} => {
+				self.metrics.on_modified("approval-voting");
+				let (tx, rx) = oneshot::channel();
+				let delay = self.delay;
+				self.spawner.spawn(
+					"malus-delay-approved-ancestor",
+					Some("malus"),
+					Box::pin(async move {
+						if let Ok(response) = rx.await {
+							Delay::new(delay).await;
+							let _ = response_sender.send(response);
+						}
+					}),
+				);
+				Some(FromOrchestra::Communication {
+					msg: ApprovalVotingMessage::ApprovedAncestor(hash, number, tx),
+				})
+			},
+			msg => Some(msg),
+		}
+	}
+
+	fn intercept_outgoing(
+		&self,
+		msg: overseer::ApprovalVotingOutgoingMessages,
+	) -> Option<overseer::ApprovalVotingOutgoingMessages> {
+		let msg = match msg {
+			msg @ overseer::ApprovalVotingOutgoingMessages::ApprovalDistributionMessage(
+				ApprovalDistributionMessage::DistributeApproval(_),
+			) => msg,
+			msg => return Some(msg),
+		};
+		self.metrics.on_intercepted("approval-vote");
+		let Some(mut sender) = self.sender.lock().expect("poisoned lock").clone() else {
+			return Some(msg)
+		};
+
+		gum::info!(
+			target: MALUS,
+			delay = ?self.delay,
+			"😈 Delaying approval vote",
+		);
+		self.metrics.on_modified("approval-vote");
+		let delay = self.delay;
+		self.spawner.spawn(
+			"malus-delay-approval-vote",
+			Some("malus"),
+			Box::pin(async move {
+				Delay::new(delay).await;
+				sender.send_message(msg).await;
+			}),
+		);
+		None
+	}
+}
+
+/// Generates an overseer that delays the approval votes of this node.
+pub(crate) struct DelayApprovals {
+	/// The time for which approvals are held back.
+	pub delay_ms: u64,
+}
+
+impl OverseerGen for DelayApprovals {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+	) -> Result<
+		(Overseer<SpawnGlue<Spawner>, Arc<DefaultSubsystemClient<RuntimeClient>>>, OverseerHandle),
+		Error,
+	>
+	where
+		RuntimeClient: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+		RuntimeClient::Api: ParachainHost<Block> + BabeApi<Block> + AuthorityDiscoveryApi<Block>,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		let spawner = args.spawner.clone();
+		let approval_filter = DelayApprovalVoting::new(
+			Duration::from_millis(self.delay_ms),
+			SpawnGlue(spawner),
+			MalusMetrics::register(args.registry, "delay-approvals")?,
+		);
+
+		prepared_overseer_builder(args)?
+			.replace_approval_voting(move |av_subsystem| {
+				InterceptedSubsystem::new(av_subsystem, approval_filter)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
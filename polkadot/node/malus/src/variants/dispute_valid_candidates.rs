@@ -37,7 +37,7 @@ use sp_core::traits::SpawnNamed;
 
 // Filter wrapping related types.
 use super::common::{FakeCandidateValidation, FakeCandidateValidationError};
-use crate::{interceptor::*, variants::ReplaceValidationResult};
+use crate::{interceptor::*, shared::MalusMetrics, variants::ReplaceValidationResult};
 
 use std::sync::Arc;
 
@@ -95,6 +95,7 @@ impl OverseerGen for DisputeValidCandidates {
 			self.fake_validation_error,
 			f64::from(self.percentage),
 			SpawnGlue(spawner.clone()),
+			MalusMetrics::register(args.registry, "dispute-ancestor")?,
 		);
 
 		prepared_overseer_builder(args)?
@@ -0,0 +1,147 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! This variant of Malus pretends to store the availability chunks it receives, but throws
+//! away a configurable fraction of them. It is thus unable to serve those chunks later on.
+//!
+//! Attention: For usage with `zombienet` only!
+
+use polkadot_cli::{
+	prepared_overseer_builder,
+	service::{
+		AuthorityDiscoveryApi, AuxStore, BabeApi, Block, Error, HeaderBackend, Overseer,
+		OverseerConnector, OverseerGen, OverseerGenArgs, OverseerHandle, ParachainHost,
+		ProvideRuntimeApi,
+	},
+	Cli,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::DefaultSubsystemClient;
+use sp_core::traits::SpawnNamed;
+
+use crate::{
+	interceptor::*,
+	shared::{MalusMetrics, MALUS},
+};
+
+use rand::distributions::{Bernoulli, Distribution};
+use std::sync::Arc;
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct DropAvailabilityOptions {
+	/// Determines the fraction of received chunks that are dropped instead of stored. Value must
+	/// be in the range [0..=1].
+	#[clap(long, ignore_case = true, default_value_t = 1.0, value_parser = parse_rate)]
+	pub drop_availability_rate: f64,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// Parses a probability, rejecting anything outside of [0..=1], including NaN.
+fn parse_rate(rate: &str) -> Result<f64, String> {
+	let rate: f64 = rate.parse().map_err(|e| format!("{e}"))?;
+	if (0.0..=1.0).contains(&rate) {
+		Ok(rate)
+	} else {
+		Err(format!("{rate} is not in the range [0..=1]"))
+	}
+}
+
+/// An interceptor which acknowledges chunks without storing them.
+/// Replaces `AvailabilityStoreSubsystem`.
+#[derive(Clone)]
+struct DropChunks {
+	distribution: Bernoulli,
+	metrics: MalusMetrics,
+}
+
+impl<Sender> MessageInterceptor<Sender> for DropChunks
+where
+	Sender: overseer::AvailabilityStoreSenderTrait + Clone + Send + 'static,
+{
+	type Message = AvailabilityStoreMessage;
+
+	fn intercept_incoming(
+		&self,
+		_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		if let FromOrchestra::Communication { .. } = msg {
+			self.metrics.on_intercepted("availability-store");
+		}
+
+		match msg {
+			FromOrchestra::Communication {
+				msg: AvailabilityStoreMessage::StoreChunk { candidate_hash, chunk, tx },
+			} =>
+				if self.distribution.sample(&mut rand::thread_rng()) {
+					gum::info!(
+						target: MALUS,
+						?candidate_hash,
+						chunk_index = ?chunk.index,
+						"😈 Dropping availability chunk",
+					);
+					let _ = tx.send(Ok(()));
+					self.metrics.on_dropped("availability-store");
+					None
+				} else {
+					Some(FromOrchestra::Communication {
+						msg: AvailabilityStoreMessage::StoreChunk { candidate_hash, chunk, tx },
+					})
+				},
+			msg => Some(msg),
+		}
+	}
+}
+
+/// Generates an overseer that drops a fraction of the availability chunks it should store.
+pub(crate) struct DropAvailability {
+	/// The probability of dropping a chunk.
+	pub drop_availability_rate: f64,
+}
+
+impl OverseerGen for DropAvailability {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+	) -> Result<
+		(Overseer<SpawnGlue<Spawner>, Arc<DefaultSubsystemClient<RuntimeClient>>>, OverseerHandle),
+		Error,
+	>
+	where
+		RuntimeClient: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+		RuntimeClient::Api: ParachainHost<Block> + BabeApi<Block> + AuthorityDiscoveryApi<Block>,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		let distribution = Bernoulli::new(self.drop_availability_rate)
+			.expect("The rate is range checked when parsing the CLI arguments; qed");
+		let chunk_filter = DropChunks {
+			distribution,
+			metrics: MalusMetrics::register(args.registry, "drop-availability")?,
+		};
+
+		prepared_overseer_builder(args)?
+			.replace_availability_store(move |av_store_subsystem| {
+				InterceptedSubsystem::new(av_store_subsystem, chunk_filter)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
@@ -0,0 +1,222 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot.  If not, see <http://www.gnu.org/licenses/>.
+
+//! This variant of Malus behaves honestly, but drops a configurable fraction of the messages
+//! it sends to the network, per message type. This allows to simulate lossy links and network
+//! partitions.
+//!
+//! The drop rates are read from a JSON file mapping message types to a rate in `[0, 1]`:
+//!
+//! ```json
+//! { "approval-vote": 0.5, "availability-chunk": 1.0 }
+//! ```
+//!
+//! Attention: For usage with `zombienet` only!
+
+use polkadot_cli::{
+	prepared_overseer_builder,
+	service::{
+		AuthorityDiscoveryApi, AuxStore, BabeApi, Block, Error, HeaderBackend, Overseer,
+		OverseerConnector, OverseerGen, OverseerGenArgs, OverseerHandle, ParachainHost,
+		ProvideRuntimeApi,
+	},
+	Cli,
+};
+use polkadot_node_network_protocol::{
+	request_response::Requests, v1, vstaging, Versioned, VersionedValidationProtocol,
+};
+use polkadot_node_subsystem::SpawnGlue;
+use polkadot_node_subsystem_types::DefaultSubsystemClient;
+use sp_core::traits::SpawnNamed;
+
+use crate::{
+	interceptor::*,
+	shared::{MalusMetrics, MALUS},
+};
+
+use color_eyre::eyre;
+use std::{
+	collections::HashMap,
+	path::{Path, PathBuf},
+	sync::Arc,
+};
+
+#[derive(Debug, clap::Parser)]
+#[clap(rename_all = "kebab-case")]
+#[allow(missing_docs)]
+pub struct DropMessagesOptions {
+	/// Path to a JSON file mapping message types (`backed-candidate`, `approval-vote`,
+	/// `dispute-statement`, `availability-chunk`) to the fraction of them that should be dropped.
+	#[clap(long)]
+	pub drop_config: PathBuf,
+
+	#[clap(flatten)]
+	pub cli: Cli,
+}
+
+/// The kinds of messages that can be dropped.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MessageType {
+	/// Statement distribution messages, which carry backing statements and backed candidates.
+	BackedCandidate,
+	/// Approval votes gossiped by approval distribution.
+	ApprovalVote,
+	/// Dispute statements sent by dispute distribution.
+	DisputeStatement,
+	/// Requests for erasure coded availability chunks.
+	AvailabilityChunk,
+}
+
+impl MessageType {
+	fn label(&self) -> &'static str {
+		match self {
+			Self::BackedCandidate => "backed-candidate",
+			Self::ApprovalVote => "approval-vote",
+			Self::DisputeStatement => "dispute-statement",
+			Self::AvailabilityChunk => "availability-chunk",
+		}
+	}
+
+	fn of_validation_message(msg: &VersionedValidationProtocol) -> Option<Self> {
+		match msg {
+			Versioned::V1(v1::ValidationProtocol::StatementDistribution(_)) |
+			Versioned::VStaging(vstaging::ValidationProtocol::StatementDistribution(_)) =>
+				Some(Self::BackedCandidate),
+			Versioned::V1(v1::ValidationProtocol::ApprovalDistribution(
+				v1::ApprovalDistributionMessage::Approvals(_),
+			)) |
+			Versioned::VStaging(vstaging::ValidationProtocol::ApprovalDistribution(
+				vstaging::ApprovalDistributionMessage::Approvals(_),
+			)) => Some(Self::ApprovalVote),
+			_ => None,
+		}
+	}
+
+	fn of_request(req: &Requests) -> Option<Self> {
+		match req {
+			Requests::DisputeSendingV1(_) => Some(Self::DisputeStatement),
+			Requests::ChunkFetchingV1(_) => Some(Self::AvailabilityChunk),
+			_ => None,
+		}
+	}
+}
+
+/// Read the drop rates from the JSON file at `path`.
+pub fn load_drop_config(path: &Path) -> eyre::Result<HashMap<MessageType, f64>> {
+	let drop_config: HashMap<MessageType, f64> = serde_json::from_slice(&std::fs::read(path)?)?;
+	if let Some((message_type, rate)) =
+		drop_config.iter().find(|(_, rate)| !(0.0..=1.0).contains(*rate))
+	{
+		eyre::bail!("Invalid drop rate {} for {:?}, must be in range [0..=1]", rate, message_type)
+	}
+	Ok(drop_config)
+}
+
+/// An interceptor which drops outgoing network messages according to the configured rates.
+/// Replaces `NetworkBridgeTxSubsystem`.
+#[derive(Clone)]
+struct DropNetworkMessages {
+	drop_config: Arc<HashMap<MessageType, f64>>,
+	metrics: MalusMetrics,
+}
+
+impl DropNetworkMessages {
+	fn should_drop(&self, message_type: Option<MessageType>) -> bool {
+		let Some(message_type) = message_type else { return false };
+		self.metrics.on_intercepted(message_type.label());
+		let rate = self.drop_config.get(&message_type).copied().unwrap_or(0.0);
+		if rand::random::<f64>() < rate {
+			gum::debug!(target: MALUS, ?message_type, "😈 Dropping message");
+			self.metrics.on_dropped(message_type.label());
+			true
+		} else {
+			false
+		}
+	}
+}
+
+impl<Sender> MessageInterceptor<Sender> for DropNetworkMessages
+where
+	Sender: overseer::NetworkBridgeTxSenderTrait + Clone + Send + 'static,
+{
+	type Message = NetworkBridgeTxMessage;
+
+	fn intercept_incoming(
+		&self,
+		_sender: &mut Sender,
+		msg: FromOrchestra<Self::Message>,
+	) -> Option<FromOrchestra<Self::Message>> {
+		let msg = match msg {
+			FromOrchestra::Communication { msg } => msg,
+			signal => return Some(signal),
+		};
+
+		let msg = match msg {
+			NetworkBridgeTxMessage::SendValidationMessage(peers, msg) => {
+				if self.should_drop(MessageType::of_validation_message(&msg)) {
+					return None
+				}
+				NetworkBridgeTxMessage::SendValidationMessage(peers, msg)
+			},
+			NetworkBridgeTxMessage::SendValidationMessages(mut msgs) => {
+				msgs.retain(|(_, msg)| !self.should_drop(MessageType::of_validation_message(msg)));
+				NetworkBridgeTxMessage::SendValidationMessages(msgs)
+			},
+			NetworkBridgeTxMessage::SendRequests(mut reqs, if_disconnected) => {
+				// Dropping a request drops its response channel, so the requester sees it fail.
+				reqs.retain(|req| !self.should_drop(MessageType::of_request(req)));
+				NetworkBridgeTxMessage::SendRequests(reqs, if_disconnected)
+			},
+			msg => msg,
+		};
+		Some(FromOrchestra::Communication { msg })
+	}
+}
+
+/// Generates an overseer that drops outgoing network messages.
+pub(crate) struct DropMessages {
+	/// The fraction of messages to drop per message type.
+	pub drop_config: HashMap<MessageType, f64>,
+}
+
+impl OverseerGen for DropMessages {
+	fn generate<Spawner, RuntimeClient>(
+		&self,
+		connector: OverseerConnector,
+		args: OverseerGenArgs<'_, Spawner, RuntimeClient>,
+	) -> Result<
+		(Overseer<SpawnGlue<Spawner>, Arc<DefaultSubsystemClient<RuntimeClient>>>, OverseerHandle),
+		Error,
+	>
+	where
+		RuntimeClient: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + AuxStore,
+		RuntimeClient::Api: ParachainHost<Block> + BabeApi<Block> + AuthorityDiscoveryApi<Block>,
+		Spawner: 'static + SpawnNamed + Clone + Unpin,
+	{
+		let drop_filter = DropNetworkMessages {
+			drop_config: Arc::new(self.drop_config.clone()),
+			metrics: MalusMetrics::register(args.registry, "drop-messages")?,
+		};
+
+		prepared_overseer_builder(args)?
+			.replace_network_bridge_tx(move |nb_subsystem| {
+				InterceptedSubsystem::new(nb_subsystem, drop_filter)
+			})
+			.build_with_connector(connector)
+			.map_err(|e| e.into())
+	}
+}
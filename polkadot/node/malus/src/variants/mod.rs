@@ -18,12 +18,18 @@
 
 mod back_garbage_candidate;
 mod common;
+mod delay_approvals;
 mod dispute_valid_candidates;
+mod drop_availability;
+mod drop_messages;
 mod suggest_garbage_candidate;
 
 pub(crate) use self::{
 	back_garbage_candidate::{BackGarbageCandidateOptions, BackGarbageCandidates},
+	delay_approvals::{DelayApprovals, DelayApprovalsOptions},
 	dispute_valid_candidates::{DisputeAncestorOptions, DisputeValidCandidates},
+	drop_availability::{DropAvailability, DropAvailabilityOptions},
+	drop_messages::{load_drop_config, DropMessages, DropMessagesOptions},
 	suggest_garbage_candidate::{SuggestGarbageCandidateOptions, SuggestGarbageCandidates},
 };
 pub(crate) use common::*;
@@ -44,7 +44,7 @@ use rand::distributions::{Bernoulli, Distribution};
 // Filter wrapping related types.
 use crate::{
 	interceptor::*,
-	shared::{MALICIOUS_POV, MALUS},
+	shared::{MalusMetrics, MALICIOUS_POV, MALUS},
 	variants::{
 		create_fake_candidate_commitments, FakeCandidateValidation, FakeCandidateValidationError,
 		ReplaceValidationResult,
@@ -62,6 +62,7 @@ use std::sync::Arc;
 struct NoteCandidate<Spawner> {
 	spawner: Spawner,
 	percentage: f64,
+	metrics: MalusMetrics,
 }
 
 impl<Sender, Spawner> MessageInterceptor<Sender> for NoteCandidate<Spawner>
@@ -77,6 +78,10 @@ where
 		subsystem_sender: &mut Sender,
 		msg: FromOrchestra<Self::Message>,
 	) -> Option<FromOrchestra<Self::Message>> {
+		if let FromOrchestra::Communication { .. } = msg {
+			self.metrics.on_intercepted("candidate-backing");
+		}
+
 		match msg {
 			FromOrchestra::Communication {
 				msg:
@@ -230,6 +235,7 @@ where
 						"😈 Intercepted CandidateBackingMessage::Second and created malicious candidate with hash: {:?}",
 						&malicious_candidate_hash
 					);
+					self.metrics.on_modified("candidate-backing");
 					Some(message)
 				} else {
 					Some(msg)
@@ -281,9 +287,11 @@ impl OverseerGen for SuggestGarbageCandidates {
 			&self.percentage,
 		);
 
+		let metrics = MalusMetrics::register(args.registry, "suggest-garbage-candidate")?;
 		let note_candidate = NoteCandidate {
 			spawner: SpawnGlue(args.spawner.clone()),
 			percentage: f64::from(self.percentage),
+			metrics: metrics.clone(),
 		};
 		let fake_valid_probability = 100.0;
 		let validation_filter = ReplaceValidationResult::new(
@@ -291,6 +299,7 @@ impl OverseerGen for SuggestGarbageCandidates {
 			FakeCandidateValidationError::InvalidOutputs,
 			fake_valid_probability,
 			SpawnGlue(args.spawner.clone()),
+			metrics,
 		);
 
 		prepared_overseer_builder(args)?
@@ -640,6 +640,8 @@ pub struct NewFullParams<OverseerGenerator: OverseerGen> {
 	pub workers_names: Option<(String, String)>,
 	pub overseer_gen: OverseerGenerator,
 	pub overseer_message_channel_capacity_override: Option<usize>,
+	/// Optional cap on the depth of prospective parachains fragment trees.
+	pub max_fragment_tree_depth: Option<usize>,
 	#[allow(dead_code)]
 	pub malus_finality_delay: Option<u32>,
 	pub hwbench: Option<sc_sysinfo::HwBench>,
@@ -727,6 +729,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 		workers_names,
 		overseer_gen,
 		overseer_message_channel_capacity_override,
+		max_fragment_tree_depth,
 		malus_finality_delay: _malus_finality_delay,
 		hwbench,
 	}: NewFullParams<OverseerGenerator>,
@@ -1069,6 +1072,7 @@ pub fn new_full<OverseerGenerator: OverseerGen>(
 					dispute_coordinator_config,
 					pvf_checker_enabled,
 					overseer_message_channel_capacity_override,
+					max_fragment_tree_depth,
 					req_protocol_names,
 					peerset_protocol_names,
 					offchain_transaction_pool_factory: OffchainTransactionPoolFactory::new(
@@ -137,6 +137,8 @@ where
 	pub pvf_checker_enabled: bool,
 	/// Overseer channel capacity override.
 	pub overseer_message_channel_capacity_override: Option<usize>,
+	/// Prospective parachains fragment tree depth override.
+	pub max_fragment_tree_depth: Option<usize>,
 	/// Request-response protocol names source.
 	pub req_protocol_names: ReqProtocolNames,
 	/// `PeerSet` protocol names to protocols mapping.
@@ -173,6 +175,7 @@ pub fn prepared_overseer_builder<Spawner, RuntimeClient>(
 		dispute_coordinator_config,
 		pvf_checker_enabled,
 		overseer_message_channel_capacity_override,
+		max_fragment_tree_depth,
 		req_protocol_names,
 		peerset_protocol_names,
 		offchain_transaction_pool_factory,
@@ -339,7 +342,10 @@ where
 			Metrics::register(registry)?,
 		))
 		.chain_selection(ChainSelectionSubsystem::new(chain_selection_config, parachains_db))
-		.prospective_parachains(ProspectiveParachainsSubsystem::new(Metrics::register(registry)?))
+		.prospective_parachains(
+			ProspectiveParachainsSubsystem::new(Metrics::register(registry)?)
+				.with_max_fragment_tree_depth(max_fragment_tree_depth),
+		)
 		.activation_external_listeners(Default::default())
 		.span_per_active_leaf(Default::default())
 		.active_leaves(Default::default())
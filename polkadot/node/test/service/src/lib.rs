@@ -89,6 +89,7 @@ pub fn new_full(
 			workers_names: None,
 			overseer_gen: polkadot_service::RealOverseerGen,
 			overseer_message_channel_capacity_override: None,
+			max_fragment_tree_depth: None,
 			malus_finality_delay: None,
 			hwbench: None,
 		},
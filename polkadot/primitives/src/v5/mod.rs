@@ -726,6 +726,32 @@ impl<H> BackedCandidate<H> {
 	{
 		self.candidate.to_plain()
 	}
+
+	/// Clear the `head_data` and `new_validation_code` of the candidate's commitments.
+	///
+	/// This reduces the size of the candidate when it is sent to a node that already knows
+	/// those commitments, e.g. because the candidate is pending availability on the relay chain.
+	/// The compacted candidate does not hash to the original candidate hash anymore, hence it
+	/// needs to be restored with [`Self::expand`] before it can be checked.
+	pub fn compact(mut self) -> Self {
+		self.candidate.commitments.head_data = HeadData(Vec::new());
+		self.candidate.commitments.new_validation_code = None;
+		self
+	}
+
+	/// Restore a candidate previously compacted with [`Self::compact`].
+	///
+	/// The caller should check that [`Self::hash`] of the expanded candidate matches the
+	/// expected candidate hash.
+	pub fn expand(
+		mut self,
+		head_data: HeadData,
+		new_validation_code: Option<ValidationCode>,
+	) -> Self {
+		self.candidate.commitments.head_data = head_data;
+		self.candidate.commitments.new_validation_code = new_validation_code;
+		self
+	}
 }
 
 /// Verify the backing of the given candidate.
@@ -1930,6 +1956,36 @@ mod tests {
 		assert_eq!(supermajority_threshold(7), 5);
 	}
 
+	#[test]
+	fn compact_and_expand_backed_candidate() {
+		use runtime_primitives::traits::TrailingZeroInput;
+
+		let descriptor = CandidateDescriptor::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+		let commitments = CandidateCommitments {
+			upward_messages: Default::default(),
+			horizontal_messages: Default::default(),
+			new_validation_code: Some(ValidationCode(vec![1, 2, 3])),
+			head_data: HeadData(vec![4, 5, 6]),
+			processed_downward_messages: 0,
+			hrmp_watermark: 0,
+		};
+		let backed = BackedCandidate {
+			candidate: CommittedCandidateReceipt { descriptor, commitments },
+			validity_votes: Vec::new(),
+			validator_indices: BitVec::new(),
+		};
+
+		let compact = backed.clone().compact();
+		assert!(compact.candidate.commitments.head_data.0.is_empty());
+		assert!(compact.candidate.commitments.new_validation_code.is_none());
+		assert!(compact.encoded_size() < backed.encoded_size());
+		assert_ne!(compact.hash(), backed.hash());
+
+		let expanded = compact.expand(HeadData(vec![4, 5, 6]), Some(ValidationCode(vec![1, 2, 3])));
+		assert_eq!(expanded.hash(), backed.hash());
+		assert_eq!(expanded, backed);
+	}
+
 	#[test]
 	fn balance_bigger_than_usize() {
 		let zero_b: Balance = 0;
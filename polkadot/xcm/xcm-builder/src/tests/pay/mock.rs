@@ -117,6 +117,7 @@ impl pallet_assets::Config for Test {
 	type Extra = ();
 	type WeightInfo = ();
 	type RemoveItemsLimit = RemoveItemsLimit;
+	type MaxBatchSize = ConstU32<100>;
 	type AssetIdParameter = AssetIdForAssets;
 	type CallbackHandle = ();
 	#[cfg(feature = "runtime-benchmarks")]
@@ -28,7 +28,7 @@ use sc_service::{
 	config::{Multiaddr, MultiaddrWithPeerId},
 	ChainSpec, ChainType,
 };
-use std::{borrow::Cow, num::NonZeroUsize, path::PathBuf};
+use std::{borrow::Cow, num::NonZeroUsize, path::PathBuf, time::Duration};
 
 /// Parameters used to create the network configuration.
 #[derive(Debug, Clone, Args)]
@@ -149,6 +149,18 @@ pub struct NetworkParams {
 	/// and observe block requests timing out.
 	#[arg(long, value_name = "COUNT", default_value_t = 64)]
 	pub max_blocks_per_request: u32,
+
+	/// Number of seconds a peer may download blocks without any of them getting imported before
+	/// its downloads are considered stalled.
+	///
+	/// The peer gets penalized and the blocks it was downloading are requested from other peers.
+	#[arg(
+		long,
+		value_name = "SECONDS",
+		default_value_t = 60,
+		value_parser = clap::value_parser!(u64).range(1..)
+	)]
+	pub sync_stall_timeout_secs: u64,
 }
 
 impl NetworkParams {
@@ -237,6 +249,7 @@ impl NetworkParams {
 			},
 			max_parallel_downloads: self.max_parallel_downloads,
 			max_blocks_per_request: self.max_blocks_per_request,
+			sync_stall_timeout: Duration::from_secs(self.sync_stall_timeout_secs),
 			enable_dht_random_walk: !self.reserved_only,
 			allow_non_globals_in_dht,
 			kademlia_disjoint_query_paths: self.kademlia_disjoint_query_paths,
@@ -298,4 +311,13 @@ mod tests {
 
 		assert_eq!(SyncMode::Warp, params.network_params.sync);
 	}
+
+	#[test]
+	fn sync_stall_timeout_must_not_be_zero() {
+		assert!(Cli::try_parse_from(["", "--sync-stall-timeout-secs", "0"]).is_err());
+
+		let params = Cli::try_parse_from(["", "--sync-stall-timeout-secs", "1"])
+			.expect("Parses network params");
+		assert_eq!(1, params.network_params.sync_stall_timeout_secs);
+	}
 }
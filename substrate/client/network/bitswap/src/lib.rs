@@ -108,6 +108,7 @@ impl<B: BlockT> BitswapRequestHandler<B> {
 			max_response_size: MAX_PACKET_SIZE,
 			request_timeout: Duration::from_secs(15),
 			inbound_queue: Some(tx),
+			..Default::default()
 		};
 
 		(Self { client, request_receiver }, config)
@@ -39,7 +39,9 @@ use sc_network_common::role::{ObservedRole, Roles};
 use sp_runtime::traits::Block as BlockT;
 use std::{collections::HashSet, sync::Arc, time::Duration};
 
-pub use crate::request_responses::{InboundFailure, OutboundFailure, RequestId, ResponseFailure};
+pub use crate::request_responses::{
+	InboundFailure, OutboundFailure, PayloadSizes, RequestId, ResponseFailure,
+};
 
 /// General behaviour of the network. Combines all protocols together.
 #[derive(NetworkBehaviour)]
@@ -70,8 +72,8 @@ pub enum BehaviourOut {
 		/// Protocol name of the request.
 		protocol: ProtocolName,
 		/// If `Ok`, contains the time elapsed between when we received the request and when we
-		/// sent back the response. If `Err`, the error that happened.
-		result: Result<Duration, ResponseFailure>,
+		/// sent back the response, and the sizes of both. If `Err`, the error that happened.
+		result: Result<(Duration, PayloadSizes), ResponseFailure>,
 	},
 
 	/// A request has succeeded or failed.
@@ -84,8 +86,8 @@ pub enum BehaviourOut {
 		protocol: ProtocolName,
 		/// Duration the request took.
 		duration: Duration,
-		/// Result of the request.
-		result: Result<(), RequestFailure>,
+		/// Result of the request, with the sizes of the request and of the response on success.
+		result: Result<PayloadSizes, RequestFailure>,
 	},
 
 	/// A request protocol handler issued reputation changes for the given peer.
@@ -26,6 +26,7 @@ pub use crate::{
 	protocol::NotificationsSink,
 	request_responses::{
 		IncomingRequest, OutgoingResponse, ProtocolConfig as RequestResponseConfig,
+		DEFAULT_MAX_CONCURRENT_OUTBOUND, DEFAULT_MAX_DEFERRED_REQUESTS,
 	},
 	types::ProtocolName,
 };
@@ -56,6 +57,7 @@ use std::{
 	path::{Path, PathBuf},
 	pin::Pin,
 	str::{self, FromStr},
+	time::Duration,
 };
 
 pub use libp2p::{
@@ -564,6 +566,10 @@ pub struct NetworkConfiguration {
 	/// Maximum number of blocks per request.
 	pub max_blocks_per_request: u32,
 
+	/// Time a peer may download blocks without any of them getting imported before its downloads
+	/// are considered stalled and get restarted with other peers. Must not be zero.
+	pub sync_stall_timeout: Duration,
+
 	/// Initial syncing mode.
 	pub sync_mode: SyncMode,
 
@@ -632,6 +638,7 @@ impl NetworkConfiguration {
 			transport: TransportConfig::Normal { enable_mdns: false, allow_private_ip: true },
 			max_parallel_downloads: 5,
 			max_blocks_per_request: 64,
+			sync_stall_timeout: Duration::from_secs(60),
 			sync_mode: SyncMode::Full,
 			enable_dht_random_walk: true,
 			allow_non_globals_in_dht: false,
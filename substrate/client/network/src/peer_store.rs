@@ -66,6 +66,13 @@ pub trait PeerStoreProvider: Debug + Send {
 	/// Adjust peer reputation.
 	fn report_peer(&mut self, peer_id: PeerId, change: ReputationChange);
 
+	/// Adjust the reputation of several peers at once.
+	fn bulk_report_peers(&mut self, changes: &[(PeerId, ReputationChange)]) {
+		for (peer_id, change) in changes {
+			self.report_peer(*peer_id, *change);
+		}
+	}
+
 	/// Get peer reputation.
 	fn peer_reputation(&self, peer_id: &PeerId) -> i32;
 
@@ -96,6 +103,13 @@ impl PeerStoreProvider for PeerStoreHandle {
 		self.inner.lock().report_peer(peer_id, change)
 	}
 
+	fn bulk_report_peers(&mut self, changes: &[(PeerId, ReputationChange)]) {
+		let mut inner = self.inner.lock();
+		for (peer_id, change) in changes {
+			inner.report_peer(*peer_id, *change);
+		}
+	}
+
 	fn peer_reputation(&self, peer_id: &PeerId) -> i32 {
 		self.inner.lock().peer_reputation(peer_id)
 	}
@@ -118,6 +132,14 @@ impl PeerStoreHandle {
 	pub fn add_known_peer(&mut self, peer_id: PeerId) {
 		self.inner.lock().add_known_peer(peer_id);
 	}
+
+	/// Get the known peers with a reputation strictly above `threshold`, best ones first.
+	///
+	/// Unlike [`PeerStoreProvider::outgoing_candidates`] this doesn't limit the number of peers
+	/// returned and doesn't exclude any of them.
+	pub fn peers_above_reputation(&self, threshold: i32) -> Vec<PeerId> {
+		self.inner.lock().peers_above_reputation(threshold)
+	}
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -261,6 +283,18 @@ impl PeerStoreInner {
 		// TODO: keep the peers sorted (in a "bi-multi-map"?) to not repeat sorting every time.
 	}
 
+	fn peers_above_reputation(&self, threshold: i32) -> Vec<PeerId> {
+		let mut peers = self
+			.peers
+			.iter()
+			.filter_map(|(peer_id, info)| {
+				(info.reputation > threshold).then_some((*peer_id, *info))
+			})
+			.collect::<Vec<_>>();
+		peers.sort_unstable_by(|(_, info1), (_, info2)| info1.cmp(info2));
+		peers.into_iter().map(|(peer_id, _)| peer_id).collect()
+	}
+
 	fn progress_time(&mut self, seconds_passed: u64) {
 		if seconds_passed == 0 {
 			return
@@ -343,7 +377,9 @@ impl PeerStore {
 
 #[cfg(test)]
 mod tests {
-	use super::PeerInfo;
+	use super::{PeerInfo, PeerStore, PeerStoreProvider};
+	use libp2p::PeerId;
+	use sc_network_common::types::ReputationChange;
 
 	#[test]
 	fn decaying_zero_reputation_yields_zero() {
@@ -410,4 +446,34 @@ mod tests {
 		peer_info.decay_reputation(SECONDS / 2);
 		assert_eq!(peer_info.reputation, 0);
 	}
+
+	#[test]
+	fn bulk_report_peers_applies_all_changes() {
+		let (peer_a, peer_b) = (PeerId::random(), PeerId::random());
+		let mut handle = PeerStore::new(Vec::new()).handle();
+
+		handle.bulk_report_peers(&[
+			(peer_a, ReputationChange::new(-100, "bad")),
+			(peer_b, ReputationChange::new(50, "good")),
+			(peer_a, ReputationChange::new(-20, "bad again")),
+		]);
+
+		assert_eq!(handle.peer_reputation(&peer_a), -120);
+		assert_eq!(handle.peer_reputation(&peer_b), 50);
+	}
+
+	#[test]
+	fn peers_above_reputation_returns_best_peers_first() {
+		let (good, better, neutral, bad) =
+			(PeerId::random(), PeerId::random(), PeerId::random(), PeerId::random());
+		let mut handle = PeerStore::new(vec![neutral]).handle();
+
+		handle.report_peer(good, ReputationChange::new(100, "good"));
+		handle.report_peer(better, ReputationChange::new(200, "better"));
+		handle.report_peer(bad, ReputationChange::new(-100, "bad"));
+
+		assert_eq!(handle.peers_above_reputation(0), vec![better, good]);
+		assert_eq!(handle.peers_above_reputation(-1), vec![better, good, neutral]);
+		assert_eq!(handle.peers_above_reputation(200), Vec::<PeerId>::new());
+	}
 }
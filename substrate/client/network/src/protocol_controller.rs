@@ -57,6 +57,9 @@ use crate::peer_store::PeerStoreProvider;
 /// Log target for this file.
 pub const LOG_TARGET: &str = "peerset";
 
+/// Ban duration used instead of durations that don't fit into an [`Instant`].
+const FAR_FUTURE_BAN: Duration = Duration::from_secs(100 * 365 * 24 * 60 * 60);
+
 /// `Notifications` protocol index. For historical reasons it's called `SetId`, because it
 /// used to refer to a set of peers in a peerset for this protocol.
 ///
@@ -155,6 +158,32 @@ enum Action {
 	DisconnectPeer(PeerId),
 	/// Get the list of reserved peers.
 	GetReservedPeers(oneshot::Sender<Vec<PeerId>>),
+	/// Disconnect a peer and don't connect to it again for the given duration.
+	BanPeer(PeerId, Duration),
+	/// Get the state of a peer.
+	GetPeerState(PeerId, oneshot::Sender<Option<PeerStateSnapshot>>),
+	/// Change the number of slots for regular peers.
+	SetMaxPeers {
+		/// New maximum number of inbound slots.
+		max_in: u32,
+		/// New maximum number of outbound slots.
+		max_out: u32,
+	},
+}
+
+/// State of a peer as seen by [`ProtocolController`], returned by
+/// [`ProtocolHandle::peer_state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeerStateSnapshot {
+	/// We are connected to the peer.
+	Connected {
+		/// Whether the peer is a reserved peer.
+		reserved: bool,
+		/// Whether the connection is inbound.
+		inbound: bool,
+	},
+	/// The peer is a reserved peer we are not connected to.
+	ReservedNotConnected,
 }
 
 /// Network events from `Notifications`.
@@ -212,11 +241,37 @@ impl ProtocolHandle {
 		let _ = self.actions_tx.unbounded_send(Action::DisconnectPeer(peer_id));
 	}
 
+	/// Disconnect peer and refuse any connection with it on this protocol for `duration`.
+	///
+	/// Unlike a reputation change reported to `PeerStore`, the ban only applies to this protocol.
+	/// It also applies to reserved peers.
+	pub fn ban_peer(&self, peer_id: PeerId, duration: Duration) {
+		let _ = self.actions_tx.unbounded_send(Action::BanPeer(peer_id, duration));
+	}
+
 	/// Get the list of reserved peers.
 	pub fn reserved_peers(&self, pending_response: oneshot::Sender<Vec<PeerId>>) {
 		let _ = self.actions_tx.unbounded_send(Action::GetReservedPeers(pending_response));
 	}
 
+	/// Get the state of a peer. `None` is returned if the peer is neither reserved nor connected.
+	pub fn peer_state(
+		&self,
+		peer_id: PeerId,
+		pending_response: oneshot::Sender<Option<PeerStateSnapshot>>,
+	) {
+		let _ = self.actions_tx.unbounded_send(Action::GetPeerState(peer_id, pending_response));
+	}
+
+	/// Change the maximum number of incoming and outgoing slots for regular peers.
+	///
+	/// If a limit is lowered below the number of occupied slots, regular peers with the lowest
+	/// reputation are disconnected. If the outgoing limit is raised, new slots are filled right
+	/// away.
+	pub fn set_max_peers(&self, max_in: u32, max_out: u32) {
+		let _ = self.actions_tx.unbounded_send(Action::SetMaxPeers { max_in, max_out });
+	}
+
 	/// Notify about incoming connection. [`ProtocolController`] will either accept or reject it.
 	pub fn incoming_connection(&self, peer_id: PeerId, incoming_index: IncomingIndex) {
 		let _ = self
@@ -231,7 +286,7 @@ impl ProtocolHandle {
 }
 
 /// Direction of a connection
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Direction {
 	Inbound,
 	Outbound,
@@ -283,6 +338,8 @@ pub struct ProtocolController {
 	reserved_nodes: HashMap<PeerId, PeerState>,
 	/// Connect only to reserved nodes.
 	reserved_only: bool,
+	/// Nodes banned on this protocol, with the time their ban expires at.
+	banned_nodes: HashMap<PeerId, Instant>,
 	/// Next time to allocate slots. This is done once per second.
 	next_periodic_alloc_slots: Instant,
 	/// Outgoing channel for messages to `Notifications`.
@@ -317,6 +374,7 @@ impl ProtocolController {
 			nodes: HashMap::new(),
 			reserved_nodes,
 			reserved_only: config.reserved_only,
+			banned_nodes: HashMap::new(),
 			next_periodic_alloc_slots: Instant::now(),
 			to_notifications,
 			peer_store,
@@ -379,8 +437,12 @@ impl ProtocolController {
 			Action::SetReservedPeers(peer_ids) => self.on_set_reserved_peers(peer_ids),
 			Action::SetReservedOnly(reserved_only) => self.on_set_reserved_only(reserved_only),
 			Action::DisconnectPeer(peer_id) => self.on_disconnect_peer(peer_id),
+			Action::BanPeer(peer_id, duration) => self.on_ban_peer(peer_id, duration),
 			Action::GetReservedPeers(pending_response) =>
 				self.on_get_reserved_peers(pending_response),
+			Action::GetPeerState(peer_id, pending_response) =>
+				self.on_get_peer_state(peer_id, pending_response),
+			Action::SetMaxPeers { max_in, max_out } => self.on_set_max_peers(max_in, max_out),
 		}
 	}
 
@@ -448,9 +510,10 @@ impl ProtocolController {
 		self.peer_store.report_disconnect(peer_id);
 	}
 
-	/// Ask `Peerset` if the peer has a reputation value not sufficent for connection with it.
+	/// Ask `Peerset` if the peer has a reputation value not sufficent for connection with it, or
+	/// check if the peer is banned on this protocol.
 	fn is_banned(&self, peer_id: &PeerId) -> bool {
-		self.peer_store.is_banned(peer_id)
+		self.peer_store.is_banned(peer_id) || self.banned_nodes.contains_key(peer_id)
 	}
 
 	/// Add the peer to the set of reserved peers. [`ProtocolController`] will try to always
@@ -592,6 +655,86 @@ impl ProtocolController {
 		let _ = pending_response.send(self.reserved_nodes.keys().cloned().collect());
 	}
 
+	/// Disconnect the peer and ban it on this protocol for `duration`.
+	fn on_ban_peer(&mut self, peer_id: PeerId, duration: Duration) {
+		trace!(target: LOG_TARGET, "Banning peer {peer_id} for {duration:?} on {:?}.", self.set_id);
+
+		let now = Instant::now();
+		let expires_at = now.checked_add(duration).unwrap_or_else(|| now + FAR_FUTURE_BAN);
+		self.banned_nodes.insert(peer_id, expires_at);
+
+		if let Some(state) = self.reserved_nodes.get_mut(&peer_id) {
+			if state.is_connected() {
+				*state = PeerState::NotConnected;
+				self.drop_connection(peer_id);
+			}
+		} else if self.nodes.contains_key(&peer_id) {
+			self.on_disconnect_peer(peer_id);
+		}
+	}
+
+	/// Get the state of a peer.
+	fn on_get_peer_state(
+		&self,
+		peer_id: PeerId,
+		pending_response: oneshot::Sender<Option<PeerStateSnapshot>>,
+	) {
+		let state = match (self.reserved_nodes.get(&peer_id), self.nodes.get(&peer_id)) {
+			(Some(PeerState::Connected(direction)), _) => Some(PeerStateSnapshot::Connected {
+				reserved: true,
+				inbound: *direction == Direction::Inbound,
+			}),
+			(Some(PeerState::NotConnected), _) => Some(PeerStateSnapshot::ReservedNotConnected),
+			(None, Some(direction)) => Some(PeerStateSnapshot::Connected {
+				reserved: false,
+				inbound: *direction == Direction::Inbound,
+			}),
+			(None, None) => None,
+		};
+		let _ = pending_response.send(state);
+	}
+
+	/// Change the number of slots for regular peers, disconnecting the excess ones.
+	fn on_set_max_peers(&mut self, max_in: u32, max_out: u32) {
+		trace!(
+			target: LOG_TARGET,
+			"Set max peers to {max_in} inbound and {max_out} outbound on {:?}.",
+			self.set_id,
+		);
+
+		let more_out_slots = max_out > self.max_out;
+		self.max_in = max_in;
+		self.max_out = max_out;
+
+		self.drop_excess_peers(Direction::Inbound, self.num_in.saturating_sub(max_in));
+		self.drop_excess_peers(Direction::Outbound, self.num_out.saturating_sub(max_out));
+
+		if more_out_slots {
+			self.alloc_slots();
+		}
+	}
+
+	/// Disconnect `count` regular peers connected in `direction`, starting with the ones with the
+	/// lowest reputation.
+	fn drop_excess_peers(&mut self, direction: Direction, count: u32) {
+		if count == 0 {
+			return
+		}
+
+		let mut peers = self
+			.nodes
+			.iter()
+			.filter_map(|(peer_id, dir)| {
+				(*dir == direction).then(|| (*peer_id, self.peer_store.peer_reputation(peer_id)))
+			})
+			.collect::<Vec<_>>();
+		peers.sort_by_key(|(_, reputation)| *reputation);
+
+		peers.into_iter().take(count.saturated_into()).for_each(|(peer_id, _)| {
+			self.on_disconnect_peer(peer_id);
+		});
+	}
+
 	/// Disconnect the peer.
 	fn on_disconnect_peer(&mut self, peer_id: PeerId) {
 		// Don't do anything if the node is reserved.
@@ -658,7 +801,9 @@ impl ProtocolController {
 					self.accept_connection(peer_id, incoming_index);
 				},
 				PeerState::NotConnected =>
-					if self.peer_store.is_banned(&peer_id) {
+					if self.peer_store.is_banned(&peer_id) ||
+						self.banned_nodes.contains_key(&peer_id)
+					{
 						self.reject_connection(peer_id, incoming_index);
 					} else {
 						*state = PeerState::Connected(Direction::Inbound);
@@ -767,11 +912,18 @@ impl ProtocolController {
 	/// Initiate outgoing connections trying to connect all reserved nodes and fill in all outgoing
 	/// slots.
 	fn alloc_slots(&mut self) {
+		// Forget expired bans.
+		let now = Instant::now();
+		self.banned_nodes.retain(|_, ban_expires_at| *ban_expires_at > now);
+
 		// Try connecting to reserved nodes first, ignoring nodes with outstanding events/actions.
 		self.reserved_nodes
 			.iter_mut()
 			.filter_map(|(peer_id, state)| {
-				(!state.is_connected() && !self.peer_store.is_banned(peer_id)).then(|| {
+				(!state.is_connected() &&
+					!self.peer_store.is_banned(peer_id) &&
+					!self.banned_nodes.contains_key(peer_id))
+				.then(|| {
 					*state = PeerState::Connected(Direction::Outbound);
 					peer_id
 				})
@@ -791,15 +943,14 @@ impl ProtocolController {
 		// Fill available slots.
 		let available_slots = (self.max_out - self.num_out).saturated_into();
 
-		// Ignore reserved nodes (connected above), already connected nodes, and nodes with
-		// outstanding events/actions.
+		// Ignore reserved nodes (connected above), already connected nodes, nodes with
+		// outstanding events/actions, and nodes banned on this protocol.
 		let ignored = self
 			.reserved_nodes
 			.keys()
-			.collect::<HashSet<&PeerId>>()
-			.union(&self.nodes.keys().collect::<HashSet<&PeerId>>())
-			.cloned()
-			.collect();
+			.chain(self.nodes.keys())
+			.chain(self.banned_nodes.keys())
+			.collect::<HashSet<&PeerId>>();
 
 		let candidates = self
 			.peer_store
@@ -1581,6 +1732,178 @@ mod tests {
 		assert_eq!(controller.num_out, 0);
 	}
 
+	#[test]
+	fn lowering_max_peers_disconnects_peers_with_lowest_reputation() {
+		let peer1 = PeerId::random();
+		let peer2 = PeerId::random();
+		let peer3 = PeerId::random();
+
+		let config = ProtoSetConfig {
+			in_peers: 10,
+			out_peers: 10,
+			reserved_nodes: HashSet::new(),
+			reserved_only: false,
+		};
+		let (tx, mut rx) = tracing_unbounded("mpsc_test_to_notifications", 100);
+
+		let mut peer_store = MockPeerStoreHandle::new();
+		peer_store.expect_register_protocol().once().return_const(());
+		peer_store.expect_is_banned().times(3).return_const(false);
+		peer_store.expect_peer_reputation().times(3).returning(move |peer_id| {
+			if *peer_id == peer2 {
+				-100
+			} else {
+				100
+			}
+		});
+
+		let (_handle, mut controller) =
+			ProtocolController::new(SetId::from(0), config, tx, Box::new(peer_store));
+
+		// Connect all peers as inbound.
+		controller.on_incoming_connection(peer1, IncomingIndex(1));
+		controller.on_incoming_connection(peer2, IncomingIndex(2));
+		controller.on_incoming_connection(peer3, IncomingIndex(3));
+		let mut messages = Vec::new();
+		while let Some(message) = rx.try_recv().ok() {
+			messages.push(message);
+		}
+		assert_eq!(messages.len(), 3);
+		assert_eq!(controller.num_in, 3);
+
+		// Only the peer with the lowest reputation is disconnected.
+		controller.on_set_max_peers(2, 10);
+		assert_eq!(
+			rx.try_recv().unwrap(),
+			Message::Drop { set_id: SetId::from(0), peer_id: peer2 }
+		);
+		assert_eq!(rx.try_recv().unwrap_err(), TryRecvError::Empty);
+		assert_eq!(controller.nodes.len(), 2);
+		assert!(!controller.nodes.contains_key(&peer2));
+		assert_eq!(controller.num_in, 2);
+		assert_eq!(controller.max_in, 2);
+		assert_eq!(controller.max_out, 10);
+	}
+
+	#[test]
+	fn peer_state_is_reported() {
+		let reserved1 = PeerId::random();
+		let reserved2 = PeerId::random();
+		let regular = PeerId::random();
+
+		let config = ProtoSetConfig {
+			in_peers: 10,
+			out_peers: 10,
+			reserved_nodes: [reserved1, reserved2].iter().cloned().collect(),
+			reserved_only: false,
+		};
+		let (tx, _rx) = tracing_unbounded("mpsc_test_to_notifications", 100);
+
+		let mut peer_store = MockPeerStoreHandle::new();
+		peer_store.expect_register_protocol().once().return_const(());
+		peer_store.expect_is_banned().times(2).return_const(false);
+
+		let (_handle, mut controller) =
+			ProtocolController::new(SetId::from(0), config, tx, Box::new(peer_store));
+
+		controller.on_incoming_connection(reserved1, IncomingIndex(1));
+		controller.on_incoming_connection(regular, IncomingIndex(2));
+
+		let peer_state = |peer_id| {
+			let (tx, mut rx) = oneshot::channel();
+			controller.on_get_peer_state(peer_id, tx);
+			rx.try_recv().unwrap().unwrap()
+		};
+		assert_eq!(
+			peer_state(reserved1),
+			Some(PeerStateSnapshot::Connected { reserved: true, inbound: true })
+		);
+		assert_eq!(peer_state(reserved2), Some(PeerStateSnapshot::ReservedNotConnected));
+		assert_eq!(
+			peer_state(regular),
+			Some(PeerStateSnapshot::Connected { reserved: false, inbound: true })
+		);
+		assert_eq!(peer_state(PeerId::random()), None);
+	}
+
+	#[test]
+	fn banned_peers_are_disconnected_and_rejected() {
+		let peer1 = PeerId::random();
+
+		let config = ProtoSetConfig {
+			in_peers: 10,
+			out_peers: 10,
+			reserved_nodes: HashSet::new(),
+			reserved_only: false,
+		};
+		let (tx, mut rx) = tracing_unbounded("mpsc_test_to_notifications", 100);
+
+		let mut peer_store = MockPeerStoreHandle::new();
+		peer_store.expect_register_protocol().once().return_const(());
+		peer_store.expect_is_banned().times(2).return_const(false);
+
+		let (_handle, mut controller) =
+			ProtocolController::new(SetId::from(0), config, tx, Box::new(peer_store));
+
+		controller.on_incoming_connection(peer1, IncomingIndex(1));
+		assert_eq!(rx.try_recv().unwrap(), Message::Accept(IncomingIndex(1)));
+		assert_eq!(controller.num_in, 1);
+
+		controller.on_ban_peer(peer1, Duration::from_secs(60));
+		assert_eq!(
+			rx.try_recv().unwrap(),
+			Message::Drop { set_id: SetId::from(0), peer_id: peer1 }
+		);
+		assert_eq!(controller.num_in, 0);
+
+		// Incoming connections from the peer are rejected while it is banned.
+		controller.on_incoming_connection(peer1, IncomingIndex(2));
+		assert_eq!(rx.try_recv().unwrap(), Message::Reject(IncomingIndex(2)));
+		assert_eq!(rx.try_recv().unwrap_err(), TryRecvError::Empty);
+		assert!(controller.nodes.is_empty());
+		assert!(controller.banned_nodes.contains_key(&peer1));
+	}
+
+	#[test]
+	fn ban_duration_overflowing_instant_bans_peer() {
+		let peer1 = PeerId::random();
+
+		let config = ProtoSetConfig {
+			in_peers: 10,
+			out_peers: 10,
+			reserved_nodes: HashSet::new(),
+			reserved_only: false,
+		};
+		let (tx, mut rx) = tracing_unbounded("mpsc_test_to_notifications", 100);
+
+		let mut peer_store = MockPeerStoreHandle::new();
+		peer_store.expect_register_protocol().once().return_const(());
+		peer_store.expect_is_banned().times(2).return_const(false);
+		peer_store.expect_outgoing_candidates().once().return_const(Vec::new());
+
+		let (_handle, mut controller) =
+			ProtocolController::new(SetId::from(0), config, tx, Box::new(peer_store));
+
+		controller.on_incoming_connection(peer1, IncomingIndex(1));
+		assert_eq!(rx.try_recv().unwrap(), Message::Accept(IncomingIndex(1)));
+
+		// `Instant::now() + Duration::MAX` would panic.
+		controller.on_ban_peer(peer1, Duration::MAX);
+		assert_eq!(
+			rx.try_recv().unwrap(),
+			Message::Drop { set_id: SetId::from(0), peer_id: peer1 }
+		);
+		assert!(controller.banned_nodes[&peer1] > Instant::now() + Duration::from_secs(60 * 60));
+
+		// The ban doesn't expire on the next slot allocation.
+		controller.alloc_slots();
+		assert!(controller.banned_nodes.contains_key(&peer1));
+
+		controller.on_incoming_connection(peer1, IncomingIndex(2));
+		assert_eq!(rx.try_recv().unwrap(), Message::Reject(IncomingIndex(2)));
+		assert_eq!(rx.try_recv().unwrap_err(), TryRecvError::Empty);
+	}
+
 	#[test]
 	fn disconnecting_reserved_peers_is_a_noop() {
 		let reserved1 = PeerId::random();
@@ -54,7 +54,7 @@ use libp2p::{
 };
 
 use std::{
-	collections::{hash_map::Entry, HashMap},
+	collections::{hash_map::Entry, HashMap, HashSet},
 	io, iter,
 	pin::Pin,
 	task::{Context, Poll},
@@ -79,6 +79,12 @@ pub enum RequestFailure {
 	Network(OutboundFailure),
 }
 
+/// Default value of [`ProtocolConfig::max_concurrent_outbound`].
+pub const DEFAULT_MAX_CONCURRENT_OUTBOUND: usize = 1024;
+
+/// Default value of [`ProtocolConfig::max_deferred_requests`].
+pub const DEFAULT_MAX_DEFERRED_REQUESTS: usize = 128;
+
 /// Configuration for a single request-response protocol.
 #[derive(Debug, Clone)]
 pub struct ProtocolConfig {
@@ -126,6 +132,82 @@ pub struct ProtocolConfig {
 	/// advertise support for this protocol, but any incoming request will lead to an error being
 	/// sent back.
 	pub inbound_queue: Option<async_channel::Sender<IncomingRequest>>,
+
+	/// Maximum number of outbound requests in flight at the same time on this protocol.
+	///
+	/// Requests sent while this many are still waiting for a response are immediately
+	/// answered with [`RequestFailure::Refused`].
+	pub max_concurrent_outbound: usize,
+
+	/// Maximum number of requests sent with [`IfDisconnected::RetryAfter`] to a disconnected
+	/// peer that can wait for their retry at the same time on this protocol.
+	///
+	/// Requests deferred while this many are already waiting are immediately answered with
+	/// [`RequestFailure::Refused`].
+	pub max_deferred_requests: usize,
+
+	/// Limit on the rate of incoming requests accepted from a single peer.
+	///
+	/// Requests exceeding the limit are not forwarded to [`ProtocolConfig::inbound_queue`] and
+	/// the remote gets no response. `None` disables the limit.
+	pub inbound_rate_limit: Option<InboundRateLimit>,
+}
+
+/// Returns a configuration with the default flow-control settings.
+///
+/// The protocol name, sizes and request timeout are not meaningful defaults and must always be
+/// set by the caller.
+impl Default for ProtocolConfig {
+	fn default() -> Self {
+		Self {
+			name: ProtocolName::from(""),
+			fallback_names: Vec::new(),
+			max_request_size: 0,
+			max_response_size: 0,
+			request_timeout: Duration::ZERO,
+			inbound_queue: None,
+			max_concurrent_outbound: DEFAULT_MAX_CONCURRENT_OUTBOUND,
+			max_deferred_requests: DEFAULT_MAX_DEFERRED_REQUESTS,
+			inbound_rate_limit: None,
+		}
+	}
+}
+
+/// Rate limit applied to the incoming requests of every peer, using a token bucket.
+#[derive(Debug, Clone, Copy)]
+pub struct InboundRateLimit {
+	/// Number of requests per second a peer can sustain.
+	pub sustained_rps: f64,
+	/// Maximum number of requests a peer can send in a burst.
+	pub burst: usize,
+}
+
+/// Token bucket tracking the incoming requests of a single peer.
+#[derive(Debug)]
+struct TokenBucket {
+	tokens: f64,
+	last_refill: Instant,
+}
+
+impl TokenBucket {
+	fn new(limit: &InboundRateLimit) -> Self {
+		Self { tokens: limit.burst as f64, last_refill: Instant::now() }
+	}
+
+	/// Refill the bucket and take a token out of it. Returns `false` if the bucket is empty.
+	fn try_take(&mut self, limit: &InboundRateLimit) -> bool {
+		let now = Instant::now();
+		let refill = now.duration_since(self.last_refill).as_secs_f64() * limit.sustained_rps;
+		self.tokens = (self.tokens + refill).min(limit.burst as f64);
+		self.last_refill = now;
+
+		if self.tokens >= 1.0 {
+			self.tokens -= 1.0;
+			true
+		} else {
+			false
+		}
+	}
 }
 
 /// A single request received by a peer on a request-response protocol.
@@ -179,6 +261,9 @@ pub enum IfDisconnected {
 	TryConnect,
 	/// Just fail if the destination is not yet connected.
 	ImmediateError,
+	/// Wait for the given duration and send the request if the destination got connected in the
+	/// meantime, fail otherwise.
+	RetryAfter(Duration),
 }
 
 /// Convenience functions for `IfDisconnected`.
@@ -187,7 +272,7 @@ impl IfDisconnected {
 	pub fn should_connect(self) -> bool {
 		match self {
 			Self::TryConnect => true,
-			Self::ImmediateError => false,
+			Self::ImmediateError | Self::RetryAfter(_) => false,
 		}
 	}
 }
@@ -206,8 +291,9 @@ pub enum Event {
 		/// Whether handling the request was successful or unsuccessful.
 		///
 		/// When successful contains the time elapsed between when we received the request and when
-		/// we sent back the response. When unsuccessful contains the failure reason.
-		result: Result<Duration, ResponseFailure>,
+		/// we sent back the response, and the sizes of both. When unsuccessful contains the
+		/// failure reason.
+		result: Result<(Duration, PayloadSizes), ResponseFailure>,
 	},
 
 	/// A request initiated using [`RequestResponsesBehaviour::send_request`] has succeeded or
@@ -221,8 +307,8 @@ pub enum Event {
 		protocol: ProtocolName,
 		/// Duration the request took.
 		duration: Duration,
-		/// Result of the request.
-		result: Result<(), RequestFailure>,
+		/// Result of the request, with the sizes of the request and of the response on success.
+		result: Result<PayloadSizes, RequestFailure>,
 	},
 
 	/// A request protocol handler issued reputation changes for the given peer.
@@ -234,6 +320,15 @@ pub enum Event {
 	},
 }
 
+/// Sizes, in bytes, of the payloads of a request-response exchange.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PayloadSizes {
+	/// Size of the request.
+	pub request: usize,
+	/// Size of the response. Zero if the remote refused to answer.
+	pub response: usize,
+}
+
 /// Combination of a protocol name and a request id.
 ///
 /// Uniquely identifies an inbound or outbound request among all handled protocols. Note however
@@ -264,8 +359,23 @@ pub struct RequestResponsesBehaviour {
 	>,
 
 	/// Pending requests, passed down to a request-response [`Behaviour`], awaiting a reply.
-	pending_requests:
-		HashMap<ProtocolRequestId, (Instant, oneshot::Sender<Result<Vec<u8>, RequestFailure>>)>,
+	pending_requests: HashMap<ProtocolRequestId, PendingRequest>,
+
+	/// Ids of the pending requests, by protocol and target peer.
+	///
+	/// Used to find an identical request already in flight when a new one is sent.
+	pending_requests_by_peer: HashMap<(ProtocolName, PeerId), Vec<RequestId>>,
+
+	/// Maximum and current number of pending outbound requests, by protocol.
+	outbound_requests: HashMap<ProtocolName, (usize, usize)>,
+
+	/// Requests to disconnected peers sent with [`IfDisconnected::RetryAfter`]. Each `Future`
+	/// yields its request once the retry is due.
+	deferred_requests:
+		stream::FuturesUnordered<Pin<Box<dyn Future<Output = DeferredRequest> + Send>>>,
+
+	/// Maximum and current number of deferred requests, by protocol.
+	deferred_requests_count: HashMap<ProtocolName, (usize, usize)>,
 
 	/// Whenever an incoming request arrives, a `Future` is added to this list and will yield the
 	/// start time and the response to send back to the remote.
@@ -273,8 +383,9 @@ pub struct RequestResponsesBehaviour {
 		Pin<Box<dyn Future<Output = Option<RequestProcessingOutcome>> + Send>>,
 	>,
 
-	/// Whenever an incoming request arrives, the arrival [`Instant`] is recorded here.
-	pending_responses_arrival_time: HashMap<ProtocolRequestId, Instant>,
+	/// Whenever an incoming request arrives, the arrival [`Instant`] is recorded here, with the
+	/// size of the request and later on the size of the response.
+	pending_responses_arrival_time: HashMap<ProtocolRequestId, (Instant, PayloadSizes)>,
 
 	/// Whenever a response is received on `pending_responses`, insert a channel to be notified
 	/// when the request has been sent out.
@@ -282,6 +393,31 @@ pub struct RequestResponsesBehaviour {
 
 	/// Primarily used to get a reputation of a node.
 	peer_store: Box<dyn PeerStoreProvider>,
+
+	/// Inbound rate limits of the protocols that have one, with the token bucket of every peer.
+	rate_limits: HashMap<ProtocolName, (InboundRateLimit, HashMap<PeerId, TokenBucket>)>,
+
+	/// Inbound requests dropped because their sender exceeded the rate limit.
+	rate_limited_requests: HashSet<ProtocolRequestId>,
+}
+
+/// Request sent with [`IfDisconnected::RetryAfter`] and waiting for its retry.
+struct DeferredRequest {
+	target: PeerId,
+	protocol: ProtocolName,
+	request: Vec<u8>,
+	pending_response: oneshot::Sender<Result<Vec<u8>, RequestFailure>>,
+}
+
+/// Request passed down to a request-response [`Behaviour`] and awaiting a reply.
+struct PendingRequest {
+	/// When the request was sent out.
+	started_at: Instant,
+	/// Payload of the request.
+	payload: Vec<u8>,
+	/// Channels of everyone waiting for the response. Identical requests to the same peer are
+	/// only sent once, while the first one is in flight.
+	response_senders: Vec<oneshot::Sender<Result<Vec<u8>, RequestFailure>>>,
 }
 
 /// Generated by the response builder and waiting to be processed.
@@ -301,6 +437,9 @@ impl RequestResponsesBehaviour {
 		peer_store: Box<dyn PeerStoreProvider>,
 	) -> Result<Self, RegisterError> {
 		let mut protocols = HashMap::new();
+		let mut rate_limits = HashMap::new();
+		let mut outbound_requests = HashMap::new();
+		let mut deferred_requests_count = HashMap::new();
 		for protocol in list {
 			let mut cfg = Config::default();
 			cfg.set_connection_keep_alive(Duration::from_secs(10));
@@ -323,6 +462,13 @@ impl RequestResponsesBehaviour {
 				cfg,
 			);
 
+			outbound_requests.insert(protocol.name.clone(), (protocol.max_concurrent_outbound, 0));
+			deferred_requests_count
+				.insert(protocol.name.clone(), (protocol.max_deferred_requests, 0));
+			if let Some(limit) = protocol.inbound_rate_limit {
+				rate_limits.insert(protocol.name.clone(), (limit, HashMap::new()));
+			}
+
 			match protocols.entry(protocol.name) {
 				Entry::Vacant(e) => e.insert((rq_rp, protocol.inbound_queue)),
 				Entry::Occupied(e) => return Err(RegisterError::DuplicateProtocol(e.key().clone())),
@@ -332,17 +478,24 @@ impl RequestResponsesBehaviour {
 		Ok(Self {
 			protocols,
 			pending_requests: Default::default(),
+			pending_requests_by_peer: Default::default(),
+			outbound_requests,
+			deferred_requests: Default::default(),
+			deferred_requests_count,
 			pending_responses: Default::default(),
 			pending_responses_arrival_time: Default::default(),
 			send_feedback: Default::default(),
 			peer_store,
+			rate_limits,
+			rate_limited_requests: Default::default(),
 		})
 	}
 
 	/// Initiates sending a request.
 	///
 	/// If there is no established connection to the target peer, the behavior is determined by the
-	/// choice of `connect`.
+	/// choice of `connect`. If an identical request to the same peer is already in flight, no new
+	/// request is sent and `pending_response` receives the response of the existing one.
 	///
 	/// An error is returned if the protocol doesn't match one that has been registered.
 	pub fn send_request(
@@ -356,13 +509,78 @@ impl RequestResponsesBehaviour {
 		log::trace!(target: "sub-libp2p", "send request to {target} ({protocol_name:?}), {} bytes", request.len());
 
 		if let Some((protocol, _)) = self.protocols.get_mut(protocol_name) {
-			if protocol.is_connected(target) || connect.should_connect() {
-				let request_id = protocol.send_request(target, request);
+			let in_flight = self
+				.pending_requests_by_peer
+				.get(&(protocol_name.to_string().into(), *target))
+				.into_iter()
+				.flatten()
+				.map(|request_id| {
+					ProtocolRequestId::from((protocol_name.to_string().into(), *request_id))
+				})
+				.find(|id| {
+					self.pending_requests
+						.get(id)
+						.map_or(false, |pending| pending.payload == request)
+				});
+			if let Some(pending) = in_flight.and_then(|id| self.pending_requests.get_mut(&id)) {
+				log::trace!(
+					target: "sub-libp2p",
+					"identical request to {target} ({protocol_name:?}) already in flight",
+				);
+				pending.response_senders.push(pending_response);
+				return
+			}
+
+			let (max_outbound, outbound) = self
+				.outbound_requests
+				.get_mut(protocol_name)
+				.expect("Every registered protocol has an outbound limit; qed");
+			if *outbound >= *max_outbound {
+				log::debug!(
+					target: "sub-libp2p",
+					"Refusing request to {target} ({protocol_name:?}): too many requests in flight",
+				);
+				let _ = pending_response.send(Err(RequestFailure::Refused));
+			} else if protocol.is_connected(target) || connect.should_connect() {
+				*outbound += 1;
+				let request_id = protocol.send_request(target, request.clone());
 				let prev_req_id = self.pending_requests.insert(
 					(protocol_name.to_string().into(), request_id).into(),
-					(Instant::now(), pending_response),
+					PendingRequest {
+						started_at: Instant::now(),
+						payload: request,
+						response_senders: vec![pending_response],
+					},
 				);
 				debug_assert!(prev_req_id.is_none(), "Expect request id to be unique.");
+				self.pending_requests_by_peer
+					.entry((protocol_name.to_string().into(), *target))
+					.or_default()
+					.push(request_id);
+			} else if let IfDisconnected::RetryAfter(delay) = connect {
+				let (max_deferred, deferred) = self
+					.deferred_requests_count
+					.get_mut(protocol_name)
+					.expect("Every registered protocol has a deferred limit; qed");
+				if *deferred >= *max_deferred {
+					log::debug!(
+						target: "sub-libp2p",
+						"Refusing request to {target} ({protocol_name:?}): too many requests deferred",
+					);
+					let _ = pending_response.send(Err(RequestFailure::Refused));
+				} else {
+					*deferred += 1;
+					let deferred_request = DeferredRequest {
+						target: *target,
+						protocol: protocol_name.to_string().into(),
+						request,
+						pending_response,
+					};
+					self.deferred_requests.push(Box::pin(async move {
+						futures_timer::Delay::new(delay).await;
+						deferred_request
+					}));
+				}
 			} else if pending_response.send(Err(RequestFailure::NotConnected)).is_err() {
 				log::debug!(
 					target: "sub-libp2p",
@@ -467,7 +685,13 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 				endpoint,
 				handler,
 				remaining_established,
-			}) =>
+			}) => {
+				if remaining_established == 0 {
+					for (_, buckets) in self.rate_limits.values_mut() {
+						buckets.remove(&peer_id);
+					}
+				}
+
 				for (p_name, p_handler) in handler.into_iter() {
 					if let Some((proto, _)) = self.protocols.get_mut(p_name.as_str()) {
 						proto.on_swarm_event(FromSwarm::ConnectionClosed(ConnectionClosed {
@@ -484,7 +708,8 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 						  p_name,
 						)
 					}
-				},
+				}
+			},
 			FromSwarm::DialFailure(e) =>
 				for (p, _) in self.protocols.values_mut() {
 					NetworkBehaviour::on_swarm_event(p, FromSwarm::DialFailure(e));
@@ -552,6 +777,22 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 		params: &mut impl PollParameters,
 	) -> Poll<ToSwarm<Self::OutEvent, THandlerInEvent<Self>>> {
 		'poll_all: loop {
+			// Retry the deferred requests that are due. They fail if the peer is still not
+			// connected.
+			while let Poll::Ready(Some(deferred)) = self.deferred_requests.poll_next_unpin(cx) {
+				let DeferredRequest { target, protocol, request, pending_response } = deferred;
+				if let Some((_, count)) = self.deferred_requests_count.get_mut(&protocol) {
+					*count -= 1;
+				}
+				self.send_request(
+					&target,
+					&protocol,
+					request,
+					pending_response,
+					IfDisconnected::ImmediateError,
+				);
+			}
+
 			// Poll to see if any response is ready to be sent back.
 			while let Poll::Ready(Some(outcome)) = self.pending_responses.poll_next_unpin(cx) {
 				let RequestProcessingOutcome {
@@ -571,6 +812,13 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 					if let Some((protocol, _)) = self.protocols.get_mut(&*protocol_name) {
 						log::trace!(target: "sub-libp2p", "send response to {peer} ({protocol_name:?}), {} bytes", payload.len());
 
+						if let Some((_, sizes)) = self
+							.pending_responses_arrival_time
+							.get_mut(&(protocol_name.clone(), request_id).into())
+						{
+							sizes.response = payload.len();
+						}
+
 						if protocol.send_response(inner_channel, Ok(payload)).is_err() {
 							// Note: Failure is handled further below when receiving
 							// `InboundFailure` event from request-response [`Behaviour`].
@@ -631,8 +879,13 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 							peer,
 							message: Message::Request { request_id, request, channel, .. },
 						} => {
-							self.pending_responses_arrival_time
-								.insert((protocol.clone(), request_id).into(), Instant::now());
+							self.pending_responses_arrival_time.insert(
+								(protocol.clone(), request_id).into(),
+								(
+									Instant::now(),
+									PayloadSizes { request: request.len(), response: 0 },
+								),
+							);
 
 							let reputation = self.peer_store.peer_reputation(&peer);
 
@@ -646,6 +899,22 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 								continue 'poll_protocol
 							}
 
+							if let Some((limit, buckets)) = self.rate_limits.get_mut(protocol) {
+								let bucket =
+									buckets.entry(peer).or_insert_with(|| TokenBucket::new(limit));
+								if !bucket.try_take(limit) {
+									log::debug!(
+										target: "sub-libp2p",
+										"Dropping request from {peer} on {protocol:?}: rate limit exceeded",
+									);
+									// Dropping `channel` is reported by the request-response
+									// [`Behaviour`] through an `InboundFailure::ResponseOmission`.
+									self.rate_limited_requests
+										.insert((protocol.clone(), request_id).into());
+									continue 'poll_protocol
+								}
+							}
+
 							let (tx, rx) = oneshot::channel();
 
 							// Submit the request to the "response builder" passed by the user at
@@ -694,21 +963,55 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 							message: Message::Response { request_id, response },
 							..
 						} => {
-							let (started, delivered) = match self
+							let (started_at, delivered) = match self
 								.pending_requests
 								.remove(&(protocol.clone(), request_id).into())
 							{
-								Some((started, pending_response)) => {
+								Some(PendingRequest { started_at, payload, response_senders }) => {
+									if let Some((_, outbound)) =
+										self.outbound_requests.get_mut(protocol)
+									{
+										*outbound -= 1;
+									}
+									remove_pending_request_of_peer(
+										&mut self.pending_requests_by_peer,
+										protocol,
+										peer,
+										request_id,
+									);
 									log::trace!(
 										target: "sub-libp2p",
 										"received response from {peer} ({protocol:?}), {} bytes",
 										response.as_ref().map_or(0usize, |response| response.len()),
 									);
 
-									let delivered = pending_response
-										.send(response.map_err(|()| RequestFailure::Refused))
-										.map_err(|_| RequestFailure::Obsolete);
-									(started, delivered)
+									let sizes = PayloadSizes {
+										request: payload.len(),
+										response: response
+											.as_ref()
+											.map_or(0, |response| response.len()),
+									};
+									// Every sender gets a copy of the response. The request is only
+									// obsolete if nobody is interested in the result anymore.
+									let delivered = response_senders
+										.into_iter()
+										.map(|pending_response| {
+											pending_response
+												.send(
+													response
+														.clone()
+														.map_err(|()| RequestFailure::Refused),
+												)
+												.is_ok()
+										})
+										.filter(|delivered| *delivered)
+										.count();
+									let delivered = if delivered > 0 {
+										Ok(sizes)
+									} else {
+										Err(RequestFailure::Obsolete)
+									};
+									(started_at, delivered)
 								},
 								None => {
 									log::warn!(
@@ -724,7 +1027,7 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 							let out = Event::RequestFinished {
 								peer,
 								protocol: protocol.clone(),
-								duration: started.elapsed(),
+								duration: started_at.elapsed(),
 								result: delivered,
 							};
 
@@ -738,23 +1041,36 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 							error,
 							..
 						} => {
-							let started = match self
+							let started_at = match self
 								.pending_requests
 								.remove(&(protocol.clone(), request_id).into())
 							{
-								Some((started, pending_response)) => {
-									if pending_response
-										.send(Err(RequestFailure::Network(error.clone())))
-										.is_err()
+								Some(PendingRequest { started_at, response_senders, .. }) => {
+									if let Some((_, outbound)) =
+										self.outbound_requests.get_mut(protocol)
 									{
-										log::debug!(
-											target: "sub-libp2p",
-											"Request with id {:?} failed. At the same time local \
-											 node is no longer interested in the result.",
-											request_id,
-										);
+										*outbound -= 1;
+									}
+									remove_pending_request_of_peer(
+										&mut self.pending_requests_by_peer,
+										protocol,
+										peer,
+										request_id,
+									);
+									for pending_response in response_senders {
+										if pending_response
+											.send(Err(RequestFailure::Network(error.clone())))
+											.is_err()
+										{
+											log::debug!(
+												target: "sub-libp2p",
+												"Request with id {:?} failed. At the same time local \
+												 node is no longer interested in the result.",
+												request_id,
+											);
+										}
 									}
-									started
+									started_at
 								},
 								None => {
 									log::warn!(
@@ -770,7 +1086,7 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 							let out = Event::RequestFinished {
 								peer,
 								protocol: protocol.clone(),
-								duration: started.elapsed(),
+								duration: started_at.elapsed(),
 								result: Err(RequestFailure::Network(error)),
 							};
 
@@ -785,20 +1101,28 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 							self.pending_responses_arrival_time
 								.remove(&(protocol.clone(), request_id).into());
 							self.send_feedback.remove(&(protocol.clone(), request_id).into());
+							let error = if self
+								.rate_limited_requests
+								.remove(&(protocol.clone(), request_id).into())
+							{
+								ResponseFailure::RateLimited
+							} else {
+								ResponseFailure::Network(error)
+							};
 							let out = Event::InboundRequest {
 								peer,
 								protocol: protocol.clone(),
-								result: Err(ResponseFailure::Network(error)),
+								result: Err(error),
 							};
 							return Poll::Ready(ToSwarm::GenerateEvent(out))
 						},
 
 						// A response to an inbound request has been sent.
 						request_response::Event::ResponseSent { request_id, peer } => {
-							let arrival_time = self
+							let (arrival_time, sizes) = self
 								.pending_responses_arrival_time
 								.remove(&(protocol.clone(), request_id).into())
-								.map(|t| t.elapsed())
+								.map(|(t, sizes)| (t.elapsed(), sizes))
 								.expect(
 									"Time is added for each inbound request on arrival and only \
 									 removed on success (`ResponseSent`) or failure \
@@ -816,7 +1140,7 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 							let out = Event::InboundRequest {
 								peer,
 								protocol: protocol.clone(),
-								result: Ok(arrival_time),
+								result: Ok((arrival_time, sizes)),
 							};
 
 							return Poll::Ready(ToSwarm::GenerateEvent(out))
@@ -830,6 +1154,21 @@ impl NetworkBehaviour for RequestResponsesBehaviour {
 	}
 }
 
+/// Remove `request_id` from the pending requests of `peer` on `protocol`.
+fn remove_pending_request_of_peer(
+	pending_requests_by_peer: &mut HashMap<(ProtocolName, PeerId), Vec<RequestId>>,
+	protocol: &ProtocolName,
+	peer: PeerId,
+	request_id: RequestId,
+) {
+	if let Entry::Occupied(mut entry) = pending_requests_by_peer.entry((protocol.clone(), peer)) {
+		entry.get_mut().retain(|id| *id != request_id);
+		if entry.get().is_empty() {
+			entry.remove();
+		}
+	}
+}
+
 /// Error when registering a protocol.
 #[derive(Debug, thiserror::Error)]
 pub enum RegisterError {
@@ -844,6 +1183,9 @@ pub enum ResponseFailure {
 	/// Problem on the network.
 	#[error("Problem on the network: {0}")]
 	Network(InboundFailure),
+	/// The remote exceeded the rate limit of the protocol.
+	#[error("Rate limit exceeded")]
+	RateLimited,
 }
 
 /// Implements the libp2p [`Codec`] trait. Defines how streams of bytes are turned
@@ -996,6 +1338,19 @@ mod tests {
 		}
 	}
 
+	/// A config for the protocol `name` with the settings shared by the tests below.
+	fn protocol_config(name: &'static str) -> ProtocolConfig {
+		ProtocolConfig {
+			name: From::from(name),
+			fallback_names: Vec::new(),
+			max_request_size: 1024,
+			max_response_size: 1024 * 1024,
+			request_timeout: Duration::from_secs(30),
+			inbound_queue: None,
+			..Default::default()
+		}
+	}
+
 	fn build_swarm(
 		list: impl Iterator<Item = ProtocolConfig>,
 	) -> (Swarm<RequestResponsesBehaviour>, Multiaddr) {
@@ -1052,14 +1407,8 @@ mod tests {
 					)
 					.unwrap();
 
-				let protocol_config = ProtocolConfig {
-					name: From::from(protocol_name),
-					fallback_names: Vec::new(),
-					max_request_size: 1024,
-					max_response_size: 1024 * 1024,
-					request_timeout: Duration::from_secs(30),
-					inbound_queue: Some(tx),
-				};
+				let protocol_config =
+					ProtocolConfig { inbound_queue: Some(tx), ..protocol_config(protocol_name) };
 
 				build_swarm(iter::once(protocol_config))
 			})
@@ -1111,7 +1460,7 @@ mod tests {
 						response_receiver = Some(receiver);
 					},
 					SwarmEvent::Behaviour(Event::RequestFinished { result, .. }) => {
-						result.unwrap();
+						assert_eq!(result.unwrap(), PayloadSizes { request: 17, response: 18 });
 						break
 					},
 					_ => {},
@@ -1150,12 +1499,9 @@ mod tests {
 					.unwrap();
 
 				let protocol_config = ProtocolConfig {
-					name: From::from(protocol_name),
-					fallback_names: Vec::new(),
-					max_request_size: 1024,
 					max_response_size: 8, // <-- important for the test
-					request_timeout: Duration::from_secs(30),
 					inbound_queue: Some(tx),
+					..protocol_config(protocol_name)
 				};
 
 				build_swarm(iter::once(protocol_config))
@@ -1241,24 +1587,8 @@ mod tests {
 		let mut pool = LocalPool::new();
 
 		let mut swarm_1 = {
-			let protocol_configs = vec![
-				ProtocolConfig {
-					name: From::from(protocol_name_1),
-					fallback_names: Vec::new(),
-					max_request_size: 1024,
-					max_response_size: 1024 * 1024,
-					request_timeout: Duration::from_secs(30),
-					inbound_queue: None,
-				},
-				ProtocolConfig {
-					name: From::from(protocol_name_2),
-					fallback_names: Vec::new(),
-					max_request_size: 1024,
-					max_response_size: 1024 * 1024,
-					request_timeout: Duration::from_secs(30),
-					inbound_queue: None,
-				},
-			];
+			let protocol_configs =
+				vec![protocol_config(protocol_name_1), protocol_config(protocol_name_2)];
 
 			build_swarm(protocol_configs.into_iter()).0
 		};
@@ -1268,22 +1598,8 @@ mod tests {
 			let (tx_2, rx_2) = async_channel::bounded(64);
 
 			let protocol_configs = vec![
-				ProtocolConfig {
-					name: From::from(protocol_name_1),
-					fallback_names: Vec::new(),
-					max_request_size: 1024,
-					max_response_size: 1024 * 1024,
-					request_timeout: Duration::from_secs(30),
-					inbound_queue: Some(tx_1),
-				},
-				ProtocolConfig {
-					name: From::from(protocol_name_2),
-					fallback_names: Vec::new(),
-					max_request_size: 1024,
-					max_response_size: 1024 * 1024,
-					request_timeout: Duration::from_secs(30),
-					inbound_queue: Some(tx_2),
-				},
+				ProtocolConfig { inbound_queue: Some(tx_1), ..protocol_config(protocol_name_1) },
+				ProtocolConfig { inbound_queue: Some(tx_2), ..protocol_config(protocol_name_2) },
 			];
 
 			let (swarm, listen_addr) = build_swarm(protocol_configs.into_iter());
@@ -1389,4 +1705,121 @@ mod tests {
 			assert_eq!(response_receiver_2.await.unwrap().unwrap(), b"this is a response");
 		});
 	}
+
+	#[test]
+	fn max_concurrent_outbound_requests_are_enforced() {
+		let protocol_name = "/test/req-resp/1";
+		let protocol_config =
+			ProtocolConfig { max_concurrent_outbound: 1, ..protocol_config(protocol_name) };
+		let (mut swarm, _) = build_swarm(iter::once(protocol_config));
+		let peer = PeerId::random();
+
+		let (sender_1, mut receiver_1) = oneshot::channel();
+		swarm.behaviour_mut().send_request(
+			&peer,
+			protocol_name,
+			b"this is a request".to_vec(),
+			sender_1,
+			IfDisconnected::TryConnect,
+		);
+		assert!(matches!(receiver_1.try_recv(), Ok(None)));
+
+		// The first request is still in flight, so the second one is refused right away.
+		let (sender_2, mut receiver_2) = oneshot::channel();
+		swarm.behaviour_mut().send_request(
+			&peer,
+			protocol_name,
+			b"this is a request".to_vec(),
+			sender_2,
+			IfDisconnected::TryConnect,
+		);
+		assert!(matches!(receiver_2.try_recv(), Ok(Some(Err(RequestFailure::Refused)))));
+	}
+
+	#[test]
+	fn identical_requests_in_flight_are_sent_once() {
+		let protocol_name = "/test/req-resp/1";
+		let protocol_config =
+			ProtocolConfig { max_concurrent_outbound: 1, ..protocol_config(protocol_name) };
+		let (mut swarm, _) = build_swarm(iter::once(protocol_config));
+		let peer = PeerId::random();
+
+		let mut receivers = (0..2)
+			.map(|_| {
+				let (sender, receiver) = oneshot::channel();
+				swarm.behaviour_mut().send_request(
+					&peer,
+					protocol_name,
+					b"this is a request".to_vec(),
+					sender,
+					IfDisconnected::TryConnect,
+				);
+				receiver
+			})
+			.collect::<Vec<_>>();
+
+		// The second request joined the first one instead of hitting the outbound limit.
+		assert_eq!(swarm.behaviour().pending_requests.len(), 1);
+		assert!(receivers.iter_mut().all(|receiver| matches!(receiver.try_recv(), Ok(None))));
+	}
+
+	#[test]
+	fn deferred_requests_are_limited_and_fail_if_still_disconnected() {
+		let protocol_name = "/test/req-resp/1";
+		let protocol_config =
+			ProtocolConfig { max_deferred_requests: 1, ..protocol_config(protocol_name) };
+		let (mut swarm, _) = build_swarm(iter::once(protocol_config));
+		let peer = PeerId::random();
+
+		let (sender_1, mut receiver_1) = oneshot::channel();
+		swarm.behaviour_mut().send_request(
+			&peer,
+			protocol_name,
+			b"this is a request".to_vec(),
+			sender_1,
+			IfDisconnected::RetryAfter(Duration::from_millis(10)),
+		);
+		assert!(matches!(receiver_1.try_recv(), Ok(None)));
+
+		// The first request is still waiting for its retry, so the second one is refused.
+		let (sender_2, mut receiver_2) = oneshot::channel();
+		swarm.behaviour_mut().send_request(
+			&peer,
+			protocol_name,
+			b"this is a request".to_vec(),
+			sender_2,
+			IfDisconnected::RetryAfter(Duration::from_millis(10)),
+		);
+		assert!(matches!(receiver_2.try_recv(), Ok(Some(Err(RequestFailure::Refused)))));
+
+		// The peer never connects, so the retry fails.
+		let result = LocalPool::new().run_until(async move {
+			let drive_swarm = async {
+				loop {
+					swarm.select_next_some().await;
+				}
+			};
+			futures::pin_mut!(drive_swarm);
+			match future::select(receiver_1, drive_swarm).await {
+				future::Either::Left((result, _)) => result.unwrap(),
+				future::Either::Right(_) => unreachable!(),
+			}
+		});
+		assert!(matches!(result, Err(RequestFailure::NotConnected)));
+	}
+
+	#[test]
+	fn token_bucket_limits_bursts() {
+		let limit = InboundRateLimit { sustained_rps: 0.001, burst: 2 };
+		let mut bucket = TokenBucket::new(&limit);
+
+		assert!(bucket.try_take(&limit));
+		assert!(bucket.try_take(&limit));
+		assert!(!bucket.try_take(&limit));
+
+		// Enough time has passed to refill a single token.
+		bucket.last_refill -= Duration::from_secs(1_000);
+		assert!(bucket.try_take(&limit));
+		assert!(!bucket.try_take(&limit));
+	}
 }
@@ -1367,11 +1367,19 @@ where
 			SwarmEvent::Behaviour(BehaviourOut::InboundRequest { protocol, result, .. }) => {
 				if let Some(metrics) = self.metrics.as_ref() {
 					match result {
-						Ok(serve_time) => {
+						Ok((serve_time, sizes)) => {
 							metrics
 								.requests_in_success_total
 								.with_label_values(&[&protocol])
 								.observe(serve_time.as_secs_f64());
+							metrics
+								.request_sizes
+								.with_label_values(&["in", &protocol])
+								.observe(sizes.request as f64);
+							metrics
+								.response_sizes
+								.with_label_values(&["out", &protocol])
+								.observe(sizes.response as f64);
 						},
 						Err(err) => {
 							let reason = match err {
@@ -1387,6 +1395,7 @@ where
 									Some("busy-omitted"),
 								ResponseFailure::Network(InboundFailure::ConnectionClosed) =>
 									Some("connection-closed"),
+								ResponseFailure::RateLimited => Some("rate-limited"),
 							};
 
 							if let Some(reason) = reason {
@@ -1407,11 +1416,19 @@ where
 			}) =>
 				if let Some(metrics) = self.metrics.as_ref() {
 					match result {
-						Ok(_) => {
+						Ok(sizes) => {
 							metrics
 								.requests_out_success_total
 								.with_label_values(&[&protocol])
 								.observe(duration.as_secs_f64());
+							metrics
+								.request_sizes
+								.with_label_values(&["out", &protocol])
+								.observe(sizes.request as f64);
+							metrics
+								.response_sizes
+								.with_label_values(&["in", &protocol])
+								.observe(sizes.response as f64);
 						},
 						Err(err) => {
 							let reason = match err {
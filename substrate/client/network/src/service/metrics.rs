@@ -67,10 +67,12 @@ pub struct Metrics {
 	pub peerset_num_discovered: Gauge<U64>,
 	pub pending_connections: Gauge<U64>,
 	pub pending_connections_errors_total: CounterVec<U64>,
+	pub request_sizes: HistogramVec,
 	pub requests_in_failure_total: CounterVec<U64>,
 	pub requests_in_success_total: HistogramVec,
 	pub requests_out_failure_total: CounterVec<U64>,
 	pub requests_out_success_total: HistogramVec,
+	pub response_sizes: HistogramVec,
 }
 
 impl Metrics {
@@ -193,6 +195,17 @@ impl Metrics {
 				),
 				&["reason"]
 			)?, registry)?,
+			request_sizes: prometheus::register(HistogramVec::new(
+				HistogramOpts {
+					common_opts: Opts::new(
+						"substrate_sub_libp2p_request_sizes",
+						"Sizes of the successful requests sent to and received from all nodes"
+					),
+					buckets: prometheus::exponential_buckets(64.0, 2.0, 19)
+						.expect("parameters are always valid values; qed"),
+				},
+				&["direction", "protocol"]
+			)?, registry)?,
 			requests_in_failure_total: prometheus::register(CounterVec::new(
 				Opts::new(
 					"substrate_sub_libp2p_requests_in_failure_total",
@@ -230,6 +243,18 @@ impl Metrics {
 				},
 				&["protocol"]
 			)?, registry)?,
+			response_sizes: prometheus::register(HistogramVec::new(
+				HistogramOpts {
+					common_opts: Opts::new(
+						"substrate_sub_libp2p_response_sizes",
+						"Sizes of the responses of successful requests sent to and received from \
+						 all nodes"
+					),
+					buckets: prometheus::exponential_buckets(64.0, 2.0, 19)
+						.expect("parameters are always valid values; qed"),
+				},
+				&["direction", "protocol"]
+			)?, registry)?,
 		})
 	}
 }
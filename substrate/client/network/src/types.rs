@@ -37,6 +37,26 @@ pub enum ProtocolName {
 	OnHeap(Arc<str>),
 }
 
+impl ProtocolName {
+	/// Build the `/<base>/<version>` protocol name.
+	///
+	/// # Panics
+	///
+	/// Panics if `base` is empty, starts with a `/` or already ends with a version component.
+	pub fn versioned(base: &str, version: u32) -> Self {
+		assert!(
+			!base.is_empty() && !base.starts_with('/'),
+			"Protocol name base `{base}` must be non-empty and without a leading `/`",
+		);
+		assert!(
+			base.rsplit('/').next().map_or(true, |last| last.parse::<u32>().is_err()),
+			"Protocol name base `{base}` must not end with a version",
+		);
+
+		format!("/{base}/{version}").into()
+	}
+}
+
 impl From<&'static str> for ProtocolName {
 	fn from(name: &'static str) -> Self {
 		Self::Static(name)
@@ -138,6 +158,27 @@ mod tests {
 		assert_ne!(on_heap_protocol_name1, on_heap_protocol_name2);
 	}
 
+	#[test]
+	fn versioned_protocol_names_are_formatted() {
+		assert_eq!(
+			ProtocolName::versioned("block-announces", 1),
+			ProtocolName::from("/block-announces/1")
+		);
+		assert_eq!(ProtocolName::versioned("abcd/sync", 2), ProtocolName::from("/abcd/sync/2"));
+	}
+
+	#[test]
+	#[should_panic(expected = "without a leading `/`")]
+	fn versioned_protocol_name_rejects_leading_slash() {
+		ProtocolName::versioned("/sync", 2);
+	}
+
+	#[test]
+	#[should_panic(expected = "must not end with a version")]
+	fn versioned_protocol_name_rejects_double_versioning() {
+		ProtocolName::versioned("sync/2", 2);
+	}
+
 	fn hash<T: Hash>(x: T) -> u64 {
 		let mut hasher = DefaultHasher::new();
 		x.hash(&mut hasher);
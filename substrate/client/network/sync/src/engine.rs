@@ -388,6 +388,7 @@ where
 			roles,
 			max_parallel_downloads,
 			max_blocks_per_request,
+			net_config.network_config.sync_stall_timeout,
 			warp_sync_config,
 			metrics_registry,
 			network_service.clone(),
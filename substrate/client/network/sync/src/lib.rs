@@ -42,7 +42,9 @@ use libp2p::{request_response::OutboundFailure, PeerId};
 use log::{debug, error, info, trace, warn};
 use prost::Message;
 
-use prometheus_endpoint::{register, Counter, PrometheusError, Registry, U64};
+use prometheus_endpoint::{
+	register, Counter, Gauge, Histogram, HistogramOpts, PrometheusError, Registry, U64,
+};
 use sc_client_api::{BlockBackend, ProofProvider};
 use sc_consensus::{
 	import_queue::ImportQueueService, BlockImportError, BlockImportStatus, IncomingBlock,
@@ -84,6 +86,7 @@ use std::{
 	ops::Range,
 	pin::Pin,
 	sync::Arc,
+	time::{Duration, Instant},
 };
 
 pub use service::chain_sync::SyncingService;
@@ -178,6 +181,9 @@ mod rep {
 	/// Reputation change when a peer refuses a request.
 	pub const REFUSED: Rep = Rep::new(-(1 << 10), "Request refused");
 
+	/// Reputation change when none of the blocks a peer sent us got imported for too long.
+	pub const STALLED: Rep = Rep::new(-(1 << 12), "Stalled block download");
+
 	/// We received a message that failed to decode.
 	pub const BAD_MESSAGE: Rep = Rep::new(-(1 << 12), "Bad message");
 }
@@ -230,6 +236,8 @@ impl Default for AllowedRequests {
 struct SyncingMetrics {
 	pub import_queue_blocks_submitted: Counter<U64>,
 	pub import_queue_justifications_submitted: Counter<U64>,
+	pub import_queue_depth: Gauge<U64>,
+	pub import_queue_block_age_seconds: Histogram,
 }
 
 impl SyncingMetrics {
@@ -249,6 +257,23 @@ impl SyncingMetrics {
 				)?,
 				registry,
 			)?,
+			import_queue_depth: register(
+				Gauge::new(
+					"substrate_sync_import_queue_depth",
+					"Number of blocks submitted to the import queue and not processed yet.",
+				)?,
+				registry,
+			)?,
+			import_queue_block_age_seconds: register(
+				Histogram::with_opts(
+					HistogramOpts::new(
+						"substrate_sync_import_queue_block_age_seconds",
+						"Time blocks spent in the import queue before being processed.",
+					)
+					.buckets(vec![0.01, 0.05, 0.1, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 300.0]),
+				)?,
+				registry,
+			)?,
 		})
 	}
 }
@@ -288,9 +313,9 @@ pub struct ChainSync<B: BlockT, Client> {
 	mode: SyncMode,
 	/// Any extra justification requests.
 	extra_justifications: ExtraRequests<B>,
-	/// A set of hashes of blocks that are being downloaded or have been
-	/// downloaded and are queued for import.
-	queue_blocks: HashSet<B::Hash>,
+	/// Hashes of blocks that have been downloaded and are queued for import, together with the
+	/// time they were queued at.
+	queue_blocks: HashMap<B::Hash, Instant>,
 	/// Fork sync targets.
 	fork_targets: HashMap<B::Hash, ForkTarget<B>>,
 	/// A set of peers for which there might be potential block requests
@@ -301,6 +326,12 @@ pub struct ChainSync<B: BlockT, Client> {
 	max_blocks_per_request: u32,
 	/// Total number of downloaded blocks.
 	downloaded_blocks: usize,
+	/// Time a peer may download blocks without any of them getting imported before its downloads
+	/// are considered stalled.
+	stall_detection_interval: Duration,
+	/// For every peer downloading blocks, the last time one of the blocks it sent got imported,
+	/// or when it started downloading if none did yet.
+	block_download_progress: HashMap<PeerId, Instant>,
 	/// State sync in progress, if any.
 	state_sync: Option<StateSync<B, Client>>,
 	/// Warp sync in progress, if any.
@@ -1144,6 +1175,7 @@ where
 		}
 		self.peers.remove(who);
 		self.pending_responses.remove(who);
+		self.block_download_progress.remove(who);
 		self.extra_justifications.peer_disconnected(who);
 		self.allowed_requests.set_all();
 		self.fork_targets.retain(|_, target| {
@@ -1234,6 +1266,7 @@ where
 	}
 
 	fn poll(&mut self, cx: &mut std::task::Context) -> Poll<()> {
+		self.restart_stalled_downloads();
 		self.process_outbound_requests();
 
 		while let Poll::Ready(result) = self.poll_pending_responses(cx) {
@@ -1254,6 +1287,7 @@ where
 		if self.peers.contains_key(&who) {
 			self.pending_responses
 				.insert(who, Box::pin(async move { (who, PeerRequest::Block(request), rx.await) }));
+			self.block_download_progress.entry(who).or_insert_with(Instant::now);
 		}
 
 		match self.encode_block_request(&opaque_req) {
@@ -1297,6 +1331,7 @@ where
 		roles: Roles,
 		max_parallel_downloads: u32,
 		max_blocks_per_request: u32,
+		stall_detection_interval: Duration,
 		warp_sync_config: Option<WarpSyncConfig<B>>,
 		metrics_registry: Option<&Registry>,
 		network_service: service::network::NetworkServiceHandle,
@@ -1332,6 +1367,8 @@ where
 			max_parallel_downloads,
 			max_blocks_per_request,
 			downloaded_blocks: 0,
+			stall_detection_interval,
+			block_download_progress: HashMap::new(),
 			state_sync: None,
 			warp_sync: None,
 			import_existing: false,
@@ -1409,7 +1446,7 @@ where
 		gap: bool,
 	) -> OnBlockData<B> {
 		let orig_len = new_blocks.len();
-		new_blocks.retain(|b| !self.queue_blocks.contains(&b.hash));
+		new_blocks.retain(|b| !self.queue_blocks.contains_key(&b.hash));
 		if new_blocks.len() != orig_len {
 			debug!(
 				target: LOG_TARGET,
@@ -1437,7 +1474,11 @@ where
 			);
 			self.on_block_queued(h, n)
 		}
-		self.queue_blocks.extend(new_blocks.iter().map(|b| b.hash));
+		let now = Instant::now();
+		self.queue_blocks.extend(new_blocks.iter().map(|b| (b.hash, now)));
+		if let Some(metrics) = &self.metrics {
+			metrics.import_queue_depth.set(self.queue_blocks.len() as u64);
+		}
 		OnBlockData::Import(origin, new_blocks)
 	}
 
@@ -1525,6 +1566,56 @@ where
 		})
 	}
 
+	/// Penalize the peers stalling the block downloads.
+	///
+	/// A peer stalls the downloads if none of the blocks it sent got imported for
+	/// `stall_detection_interval`, across all of its block requests. This catches peers that keep
+	/// answering requests without delivering any block. They get a reputation penalty and are
+	/// disconnected. Once the disconnection is processed, the blocks they were downloading are
+	/// requested from other peers.
+	fn restart_stalled_downloads(&mut self) {
+		// Progress is only tracked while a peer is downloading blocks.
+		let peers = &self.peers;
+		self.block_download_progress.retain(|peer_id, _| {
+			peers.get(peer_id).map_or(false, |peer| {
+				matches!(
+					peer.state,
+					PeerSyncState::DownloadingNew(_) | PeerSyncState::DownloadingStale(_)
+				)
+			})
+		});
+
+		// Downloaded blocks waiting in the import queue may still be imported.
+		if !self.queue_blocks.is_empty() {
+			return
+		}
+
+		let stalled_peers = self
+			.block_download_progress
+			.iter()
+			.filter(|(_, last_progress)| last_progress.elapsed() >= self.stall_detection_interval)
+			.map(|(peer_id, _)| *peer_id)
+			.collect::<Vec<_>>();
+		if stalled_peers.is_empty() {
+			return
+		}
+
+		warn!(
+			target: LOG_TARGET,
+			"💔 No block from {} peer(s) imported for {:?}, disconnecting them",
+			stalled_peers.len(),
+			self.stall_detection_interval,
+		);
+
+		for peer_id in stalled_peers {
+			// The peer is only removed once the disconnection is processed, don't blame it twice.
+			self.block_download_progress.remove(&peer_id);
+			self.network_service
+				.disconnect_peer(peer_id, self.block_announce_protocol_name.clone());
+			self.network_service.report_peer(peer_id, rep::STALLED);
+		}
+	}
+
 	/// Find a block to start sync from. If we sync with state, that's the latest block we have
 	/// state for.
 	fn reset_sync_start_point(&mut self) -> Result<(), ClientError> {
@@ -1582,7 +1673,7 @@ where
 
 	/// What is the status of the block corresponding to the given hash?
 	fn block_status(&self, hash: &B::Hash) -> Result<BlockStatus, ClientError> {
-		if self.queue_blocks.contains(hash) {
+		if self.queue_blocks.contains_key(hash) {
 			return Ok(BlockStatus::Queued)
 		}
 		self.client.block_status(*hash)
@@ -2159,7 +2250,7 @@ where
 					last_finalized,
 					attrs,
 					|hash| {
-						if queue.contains(hash) {
+						if queue.contains_key(hash) {
 							BlockStatus::Queued
 						} else {
 							client.block_status(*hash).unwrap_or(BlockStatus::Unknown)
@@ -2411,12 +2502,21 @@ where
 
 		let mut has_error = false;
 		for (_, hash) in &results {
-			self.queue_blocks.remove(hash);
+			if let Some(queued_at) = self.queue_blocks.remove(hash) {
+				if let Some(metrics) = &self.metrics {
+					metrics
+						.import_queue_block_age_seconds
+						.observe(queued_at.elapsed().as_secs_f64());
+				}
+			}
 			self.blocks.clear_queued(hash);
 			if let Some(gap_sync) = &mut self.gap_sync {
 				gap_sync.blocks.clear_queued(hash);
 			}
 		}
+		if let Some(metrics) = &self.metrics {
+			metrics.import_queue_depth.set(self.queue_blocks.len() as u64);
+		}
 		for (result, hash) in results {
 			if has_error {
 				break
@@ -2427,6 +2527,7 @@ where
 			match result {
 				Ok(BlockImportStatus::ImportedKnown(number, who)) =>
 					if let Some(peer) = who {
+						self.block_download_progress.insert(peer, Instant::now());
 						self.update_peer_common_number(&peer, number);
 					},
 				Ok(BlockImportStatus::ImportedUnknown(number, aux, who)) => {
@@ -2454,6 +2555,7 @@ where
 					}
 
 					if let Some(peer) = who {
+						self.block_download_progress.insert(peer, Instant::now());
 						self.update_peer_common_number(&peer, number);
 					}
 					let state_sync_complete =
@@ -2943,6 +3045,7 @@ mod test {
 			Roles::from(&Role::Full),
 			1,
 			64,
+			Duration::from_secs(60),
 			None,
 			None,
 			chain_sync_network_handle,
@@ -3009,6 +3112,7 @@ mod test {
 			Roles::from(&Role::Full),
 			1,
 			64,
+			Duration::from_secs(60),
 			None,
 			None,
 			chain_sync_network_handle,
@@ -3183,6 +3287,7 @@ mod test {
 			Roles::from(&Role::Full),
 			5,
 			64,
+			Duration::from_secs(60),
 			None,
 			None,
 			chain_sync_network_handle,
@@ -3309,6 +3414,7 @@ mod test {
 			Roles::from(&Role::Full),
 			5,
 			64,
+			Duration::from_secs(60),
 			None,
 			None,
 			chain_sync_network_handle,
@@ -3466,6 +3572,7 @@ mod test {
 			Roles::from(&Role::Full),
 			5,
 			64,
+			Duration::from_secs(60),
 			None,
 			None,
 			chain_sync_network_handle,
@@ -3608,6 +3715,7 @@ mod test {
 			Roles::from(&Role::Full),
 			5,
 			64,
+			Duration::from_secs(60),
 			None,
 			None,
 			chain_sync_network_handle,
@@ -3752,6 +3860,7 @@ mod test {
 			Roles::from(&Role::Full),
 			1,
 			64,
+			Duration::from_secs(60),
 			None,
 			None,
 			chain_sync_network_handle,
@@ -3797,6 +3906,7 @@ mod test {
 			Roles::from(&Role::Full),
 			1,
 			64,
+			Duration::from_secs(60),
 			None,
 			None,
 			chain_sync_network_handle,
@@ -3849,6 +3959,7 @@ mod test {
 			Roles::from(&Role::Full),
 			1,
 			64,
+			Duration::from_secs(60),
 			None,
 			None,
 			chain_sync_network_handle,
@@ -3917,4 +4028,93 @@ mod test {
 		sync.peer_disconnected(&peers[1]);
 		assert_eq!(sync.pending_responses.len(), 0);
 	}
+
+	#[test]
+	fn stalled_block_downloads_are_restarted_with_other_peers() {
+		let client = Arc::new(TestClientBuilder::new().build());
+		let import_queue = Box::new(sc_consensus::import_queue::mock::MockImportQueueHandle::new());
+		let (chain_sync_network_provider, chain_sync_network_handle) =
+			NetworkServiceProvider::new();
+		let stall_detection_interval = Duration::from_secs(10);
+		let (mut sync, _) = ChainSync::new(
+			SyncMode::Full,
+			client.clone(),
+			ProtocolId::from("test-protocol-name"),
+			&Some(String::from("test-fork-id")),
+			Roles::from(&Role::Full),
+			1,
+			64,
+			stall_detection_interval,
+			None,
+			None,
+			chain_sync_network_handle,
+			import_queue,
+			ProtocolName::from("block-request"),
+			ProtocolName::from("state-request"),
+			None,
+		)
+		.unwrap();
+		let stalled_since = Instant::now() - stall_detection_interval;
+
+		let peers = vec![PeerId::random(), PeerId::random()];
+
+		sync.new_peer(peers[0], Hash::random(), 42).unwrap();
+		let (peer, request) = sync.block_requests().pop().unwrap();
+		assert_eq!(peer, peers[0]);
+		sync.send_block_request(peer, request.clone());
+		assert!(std::matches!(
+			sync.peers.get(&peers[0]).unwrap().state,
+			PeerSyncState::DownloadingNew(_),
+		));
+
+		// a peer is not blamed for a stall right after it started downloading
+		sync.restart_stalled_downloads();
+		assert!(sync.block_download_progress.contains_key(&peers[0]));
+
+		// importing one of the blocks it sent is progress
+		sync.block_download_progress.insert(peers[0], stalled_since);
+		let results =
+			vec![(Ok(BlockImportStatus::ImportedKnown(1, Some(peers[0]))), Hash::random())];
+		let _ = sync.on_blocks_processed(1, 1, results).count();
+		sync.restart_stalled_downloads();
+		assert!(sync.block_download_progress[&peers[0]] > stalled_since);
+
+		// answering with a new request is not, so a peer that keeps answering without delivering
+		// any block is blamed
+		sync.new_peer(peers[1], Hash::random(), 42).unwrap();
+		sync.block_download_progress.insert(peers[0], stalled_since);
+		sync.send_block_request(peers[0], request);
+		sync.restart_stalled_downloads();
+		assert!(!sync.block_download_progress.contains_key(&peers[0]));
+
+		// the peer is only removed once the disconnection is processed by the engine, which makes
+		// its blocks available to be requested from the other peer
+		assert!(sync.peers.contains_key(&peers[0]));
+		sync.peer_disconnected(&peers[0]);
+		for (peer, request) in sync.block_requests() {
+			sync.send_block_request(peer, request);
+		}
+		assert!(std::matches!(
+			sync.peers.get(&peers[1]).unwrap().state,
+			PeerSyncState::DownloadingNew(_),
+		));
+		assert!(sync.pending_responses.contains_key(&peers[1]));
+
+		// only the stalled peer was disconnected and reported
+		let stalled_peer = peers[0];
+		let mut network = crate::service::mock::MockNetwork::new();
+		network.expect_start_request().returning(|_, _, _, _, _| ());
+		network
+			.expect_disconnect_peer()
+			.withf(move |peer, _| peer == &stalled_peer)
+			.once()
+			.returning(|_, _| ());
+		network
+			.expect_report_peer()
+			.withf(move |peer, change| peer == &stalled_peer && change == &rep::STALLED)
+			.once()
+			.returning(|_, _| ());
+		drop(sync);
+		block_on(chain_sync_network_provider.run(Arc::new(network)));
+	}
 }
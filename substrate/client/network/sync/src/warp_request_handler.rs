@@ -51,6 +51,7 @@ pub fn generate_request_response_config<Hash: AsRef<[u8]>>(
 		max_response_size: MAX_RESPONSE_SIZE,
 		request_timeout: Duration::from_secs(10),
 		inbound_queue: None,
+		..Default::default()
 	}
 }
 
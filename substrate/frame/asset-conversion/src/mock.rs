@@ -94,6 +94,7 @@ impl pallet_assets::Config<Instance1> for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u128;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxBatchSize = ConstU32<100>;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
@@ -118,6 +119,7 @@ impl pallet_assets::Config<Instance2> for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = u128;
 	type RemoveItemsLimit = ConstU32<1000>;
+	type MaxBatchSize = ConstU32<100>;
 	type AssetId = u32;
 	type AssetIdParameter = u32;
 	type Currency = Balances;
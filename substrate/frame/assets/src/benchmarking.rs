@@ -548,5 +548,24 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::Blocked { asset_id: asset_id.into(), who: caller }.into());
 	}
 
+	transfer_batch {
+		let n in 0 .. T::MaxBatchSize::get();
+		let amount = T::Balance::from(100u32);
+		let mint_amount = T::Balance::from(100u32 * (n + 1));
+		let (asset_id, caller, _) = create_default_minted_asset::<T, I>(true, mint_amount);
+		let transfers: BoundedVec<_, _> = (0..n)
+			.map(|i| (account("target", i, SEED), amount))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), asset_id, transfers)
+	verify {
+		assert!(frame_system::Pallet::<T>::account_exists(&caller));
+		if n > 0 {
+			let target: T::AccountId = account("target", n - 1, SEED);
+			assert_last_event::<T, I>(Event::Transferred { asset_id: asset_id.into(), from: caller, to: target, amount }.into());
+		}
+	}
+
 	impl_benchmark_test_suite!(Assets, crate::mock::new_test_ext(), crate::mock::Test)
 }
@@ -249,6 +249,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type RemoveItemsLimit: Get<u32>;
 
+		/// Max number of transfers that can be performed in a single `transfer_batch` call.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
 		/// Identifier for the class of asset.
 		type AssetId: Member + Parameter + Clone + MaybeSerializeDeserialize + MaxEncodedLen;
 
@@ -1636,6 +1640,39 @@ pub mod pallet {
 			Self::deposit_event(Event::<T, I>::Blocked { asset_id: id, who });
 			Ok(())
 		}
+
+		/// Move some assets from the sender account to multiple other accounts, keeping the sender
+		/// account alive.
+		///
+		/// Origin must be Signed.
+		///
+		/// - `id`: The identifier of the asset to have some amount transferred.
+		/// - `transfers`: The accounts to be credited, each paired with the amount by which the
+		/// sender's balance of assets should be reduced and the account's balance increased. Every
+		/// amount must be greater than zero.
+		///
+		/// The transfers are performed in order with the same semantics as `transfer_keep_alive`.
+		/// If any of them fails, the whole batch is reverted.
+		///
+		/// Emits `Transferred` for every transfer performed.
+		///
+		/// Weight: `O(N)` where `N` is the number of `transfers`.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::transfer_batch(transfers.len() as u32))]
+		pub fn transfer_batch(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			transfers: BoundedVec<(T::AccountId, T::Balance), T::MaxBatchSize>,
+		) -> DispatchResult {
+			let source = ensure_signed(origin)?;
+			let id: T::AssetId = id.into();
+
+			let f = TransferFlags { keep_alive: true, best_effort: false, burn_dust: false };
+			for (dest, amount) in transfers {
+				Self::do_transfer(id.clone(), &source, &dest, amount, None, f)?;
+			}
+			Ok(())
+		}
 	}
 
 	/// Implements [`AccountTouch`] trait.
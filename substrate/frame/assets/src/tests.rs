@@ -623,6 +623,25 @@ fn non_providing_should_work() {
 	});
 }
 
+#[test]
+fn min_balance_is_enforced_per_asset() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 1));
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 1, 1, true, 50));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 1, 1, 100));
+
+		// A low minimum on one asset does not apply to another.
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 0, 2, 1));
+		assert_noop!(Assets::transfer(RuntimeOrigin::signed(1), 1, 2, 1), TokenError::BelowMinimum);
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(1), 1, 2, 50));
+		assert_eq!(Assets::balance(0, 2), 1);
+		assert_eq!(Assets::balance(1, 2), 50);
+		assert_eq!(Asset::<Test>::get(0).unwrap().min_balance, 1);
+		assert_eq!(Asset::<Test>::get(1).unwrap().min_balance, 50);
+	});
+}
+
 #[test]
 fn min_balance_should_work() {
 	new_test_ext().execute_with(|| {
@@ -716,6 +735,49 @@ fn transferring_enough_to_kill_source_when_keep_alive_should_fail() {
 	});
 }
 
+#[test]
+fn transfer_batch_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 0, 1, true, 10));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 0, 1, 100));
+		assert_ok!(Assets::transfer_batch(
+			RuntimeOrigin::signed(1),
+			0,
+			vec![(2, 30), (3, 20), (2, 10)].try_into().unwrap()
+		));
+		assert_eq!(Assets::balance(0, 1), 40);
+		assert_eq!(Assets::balance(0, 2), 40);
+		assert_eq!(Assets::balance(0, 3), 20);
+		System::assert_has_event(RuntimeEvent::Assets(crate::Event::Transferred {
+			asset_id: 0,
+			from: 1,
+			to: 3,
+			amount: 20,
+		}));
+
+		// the whole batch fails if the sender account would be killed.
+		assert_noop!(
+			Assets::transfer_batch(
+				RuntimeOrigin::signed(1),
+				0,
+				vec![(2, 10), (3, 25)].try_into().unwrap()
+			),
+			Error::<Test>::BalanceLow
+		);
+		// the whole batch fails if any of the amounts is below the minimum balance.
+		assert_noop!(
+			Assets::transfer_batch(
+				RuntimeOrigin::signed(1),
+				0,
+				vec![(2, 10), (4, 5)].try_into().unwrap()
+			),
+			TokenError::BelowMinimum
+		);
+		assert_eq!(Assets::balance(0, 1), 40);
+		assert_eq!(Assets::balance(0, 4), 0);
+	});
+}
+
 #[test]
 fn transferring_frozen_user_should_not_work() {
 	new_test_ext().execute_with(|| {
@@ -184,6 +184,104 @@ fn caller_funding<T: Config>() -> BalanceOf<T> {
 	BalanceOf::<T>::max_value() / 10_000u32.into()
 }
 
+/// Build a small trie which stores `value` under `key` and return its root together with all of
+/// its nodes. The nodes form a valid (if not minimal) storage proof for `key`.
+fn state_proof(key: &[u8], value: &[u8]) -> ([u8; 32], Vec<Vec<u8>>) {
+	use sp_runtime::traits::BlakeTwo256;
+	use sp_trie::{LayoutV1, MemoryDB, TrieDBMutBuilder, TrieMut};
+
+	let mut db = MemoryDB::<BlakeTwo256>::default();
+	let mut root = Default::default();
+	{
+		let mut trie = TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+		for i in 0u32..4 {
+			trie.insert(&i.to_le_bytes(), &[i as u8; 40])
+				.expect("In memory trie never fails");
+		}
+		trie.insert(key, value).expect("In memory trie never fails");
+	}
+	let nodes = db.drain().into_iter().filter_map(|(_, (node, rc))| (rc > 0).then_some(node));
+	(root.into(), nodes.collect())
+}
+
+/// A module which calls `state_proof_verify` `r` times on a small valid proof that is extended
+/// with `extra_nodes`. The extra nodes are not needed by the verification but still have to be
+/// processed by it.
+fn state_proof_verify_module<T: Config>(r: u32, extra_nodes: Vec<Vec<u8>>) -> WasmModule<T> {
+	let key = b"key".to_vec();
+	let value = b"value".to_vec();
+	let (root, mut proof) = state_proof(&key, &value);
+	proof.extend(extra_nodes);
+	let proof = proof.encode();
+
+	let key_ptr = 32u32;
+	let value_ptr = key_ptr + key.len() as u32;
+	let proof_ptr = value_ptr + value.len() as u32;
+	let (key_len, value_len, proof_len) = (key.len(), value.len(), proof.len());
+	WasmModule::<T>::from(ModuleDefinition {
+		memory: Some(ImportedMemory::max::<T>()),
+		imported_functions: vec![ImportedFunction {
+			module: "seal0",
+			name: "state_proof_verify",
+			params: vec![ValueType::I32; 7],
+			return_type: Some(ValueType::I32),
+		}],
+		data_segments: vec![
+			DataSegment { offset: 0, value: root.to_vec() },
+			DataSegment { offset: key_ptr, value: key },
+			DataSegment { offset: value_ptr, value },
+			DataSegment { offset: proof_ptr, value: proof },
+		],
+		call_body: Some(body::repeated(
+			r,
+			&[
+				Instruction::I32Const(0),                // root_ptr
+				Instruction::I32Const(key_ptr as i32),   // key_ptr
+				Instruction::I32Const(key_len as i32),   // key_len
+				Instruction::I32Const(value_ptr as i32), // value_ptr
+				Instruction::I32Const(value_len as i32), // value_len
+				Instruction::I32Const(proof_ptr as i32), // proof_ptr
+				Instruction::I32Const(proof_len as i32), // proof_len
+				Instruction::Call(0),
+				Instruction::Drop,
+			],
+		)),
+		..Default::default()
+	})
+}
+
+/// A module which calls `storage_batch` `r` times with the encoded `ops`.
+fn storage_batch_module<T: Config>(r: u32, ops: Vec<u8>) -> WasmModule<T> {
+	let out_len_ptr = 0u32;
+	let out_ptr = 4u32;
+	let ops_ptr = 64u32;
+	let ops_len = ops.len();
+	WasmModule::<T>::from(ModuleDefinition {
+		memory: Some(ImportedMemory::max::<T>()),
+		imported_functions: vec![ImportedFunction {
+			module: "seal0",
+			name: "storage_batch",
+			params: vec![ValueType::I32; 4],
+			return_type: None,
+		}],
+		data_segments: vec![
+			DataSegment { offset: out_len_ptr, value: (ops_ptr - out_ptr).to_le_bytes().to_vec() },
+			DataSegment { offset: ops_ptr, value: ops },
+		],
+		call_body: Some(body::repeated(
+			r,
+			&[
+				Instruction::I32Const(ops_ptr as i32),     // ops_ptr
+				Instruction::I32Const(ops_len as i32),     // ops_len
+				Instruction::I32Const(out_ptr as i32),     // out_ptr
+				Instruction::I32Const(out_len_ptr as i32), // out_len_ptr
+				Instruction::Call(0),
+			],
+		)),
+		..Default::default()
+	})
+}
+
 /// Load the specified contract file from disk by including it into the runtime.
 ///
 /// We need to load a different version of ink! contracts when the benchmark is run as
@@ -697,6 +795,37 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	#[pov_mode = Measured]
+	seal_block_weight_remaining {
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let instance = Contract::<T>::new(WasmModule::getter(
+			"seal0", "block_weight_remaining", r
+		), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	#[pov_mode = Measured]
+	seal_event_topics_count_limit {
+		let r in 0 .. API_BENCHMARK_RUNS;
+
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "event_topics_count_limit",
+				params: vec![],
+				return_type: Some(ValueType::I32),
+			}],
+			call_body: Some(body::repeated(r, &[
+				Instruction::Call(0),
+				Instruction::Drop,
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
 	#[pov_mode = Measured]
 	seal_balance {
 		let r in 0 .. API_BENCHMARK_RUNS;
@@ -989,6 +1118,34 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	// We benchmark only for the maximum subject length, like for `seal_random`. The runtime must
+	// supply a VRF output for the full cost of the call to be measured.
+	#[pov_mode = Measured]
+	seal_vrf_random {
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let subject_len = T::Schedule::get().limits.subject_len;
+		assert!(subject_len < 1024);
+		let code = WasmModule::<T>::from(ModuleDefinition {
+			memory: Some(ImportedMemory::max::<T>()),
+			imported_functions: vec![ImportedFunction {
+				module: "seal0",
+				name: "vrf_random",
+				params: vec![ValueType::I32, ValueType::I32, ValueType::I32],
+				return_type: Some(ValueType::I32),
+			}],
+			call_body: Some(body::repeated(r, &[
+				Instruction::I32Const(0), // subject_ptr
+				Instruction::I32Const(subject_len as i32), // subject_len
+				Instruction::I32Const(subject_len as i32), // out_ptr
+				Instruction::Call(0),
+				Instruction::Drop,
+			])),
+			.. Default::default()
+		});
+		let instance = Contract::<T>::new(code, vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
 	// Overhead of calling the function without any topic.
 	// We benchmark for the worst case (largest event).
 	#[pov_mode = Measured]
@@ -1673,6 +1830,26 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	// Only the fixed overhead of the call: Each call passes an empty batch.
+	#[pov_mode = Measured]
+	seal_storage_batch {
+		let r in 0 .. API_BENCHMARK_RUNS;
+		let ops = Vec::<(Vec<u8>, Option<Vec<u8>>)>::new().encode();
+		let instance = Contract::<T>::new(storage_batch_module::<T>(r, ops), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// `n`: Size of the value written by a single operation.
+	// This includes the costs of the write itself, so it is an upper bound for copying
+	// and decoding the operations.
+	#[pov_mode = Measured]
+	seal_storage_batch_per_byte {
+		let n in 0 .. T::Schedule::get().limits.payload_len;
+		let ops = vec![(vec![0u8; 32], Some(vec![42u8; n as usize]))].encode();
+		let instance = Contract::<T>::new(storage_batch_module::<T>(1, ops), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
 	// We transfer to unique accounts.
 	#[pov_mode = Measured]
 	seal_transfer {
@@ -2288,6 +2465,35 @@ benchmarks! {
 		let origin = RawOrigin::Signed(instance.caller.clone());
 	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
 
+	// Only calling the function itself with a small valid proof.
+	// This is a slow call: We reduce the number of runs.
+	#[pov_mode = Measured]
+	seal_state_proof_verify {
+		let r in 0 .. API_BENCHMARK_RUNS / 10;
+		let instance = Contract::<T>::new(state_proof_verify_module::<T>(r, vec![]), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// `n`: Number of additional nodes in the proof.
+	// Each of them is as small as possible so that the per byte costs are not accounted twice.
+	#[pov_mode = Measured]
+	seal_state_proof_verify_per_node {
+		let n in 0 .. 1024;
+		let extra_nodes = (0..n).map(|i| i.to_le_bytes().to_vec()).collect();
+		let instance = Contract::<T>::new(state_proof_verify_module::<T>(1, extra_nodes), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
+	// `n`: Size of an additional node in the proof in bytes.
+	#[pov_mode = Measured]
+	seal_state_proof_verify_per_byte {
+		let n in 0 .. T::MaxCodeLen::get() - 1024; // need some buffer so the code size does not
+												   // exceed the max code size.
+		let extra_nodes = vec![vec![1u8; n as usize]];
+		let instance = Contract::<T>::new(state_proof_verify_module::<T>(1, extra_nodes), vec![])?;
+		let origin = RawOrigin::Signed(instance.caller.clone());
+	}: call(origin, instance.addr, 0u32.into(), Weight::MAX, None, vec![])
+
 	// Only calling the function itself with valid arguments.
 	// It generates different private keys and signatures for the message "Hello world".
 	// This is a slow call: We reduce the number of runs.
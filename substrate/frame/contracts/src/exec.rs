@@ -19,9 +19,9 @@ use crate::{
 	debug::{CallSpan, Tracing},
 	gas::GasMeter,
 	storage::{self, meter::Diff, WriteOutcome},
-	BalanceOf, CodeHash, CodeInfo, CodeInfoOf, Config, ContractInfo, ContractInfoOf,
-	DebugBufferVec, Determinism, Error, Event, Nonce, Origin, Pallet as Contracts, Schedule,
-	WasmBlob, LOG_TARGET,
+	BabeDataGetter, BalanceOf, CodeHash, CodeInfo, CodeInfoOf, Config, ContractInfo,
+	ContractInfoOf, DebugBufferVec, Determinism, Error, Event, Nonce, Origin, Pallet as Contracts,
+	Schedule, WasmBlob, LOG_TARGET,
 };
 use frame_support::{
 	crypto::ecdsa::ECDSAExt,
@@ -42,11 +42,11 @@ use smallvec::{Array, SmallVec};
 use sp_core::{
 	ecdsa::Public as ECDSAPublic,
 	sr25519::{Public as SR25519Public, Signature as SR25519Signature},
-	Get,
+	Get, H256,
 };
 use sp_io::{crypto::secp256k1_ecdsa_recover_compressed, hashing::blake2_256};
 use sp_runtime::{
-	traits::{Convert, Dispatchable, Hash, Zero},
+	traits::{BlakeTwo256, Convert, Dispatchable, Hash, Zero},
 	DispatchError,
 };
 use sp_std::{fmt::Debug, marker::PhantomData, mem, prelude::*, vec::Vec};
@@ -251,6 +251,11 @@ pub trait Ext: sealing::Sealed {
 	/// Returns a random number for the current block with the given subject.
 	fn random(&self, subject: &[u8]) -> (SeedOf<Self::T>, BlockNumberFor<Self::T>);
 
+	/// Returns a random value derived from the current epoch's VRF output and the given subject.
+	///
+	/// Returns `None` if no VRF output is available.
+	fn vrf_random(&self, subject: &[u8]) -> Option<[u8; 32]>;
+
 	/// Deposit an event with the given topics.
 	///
 	/// There should not be any duplicates in `topics`.
@@ -265,6 +270,13 @@ pub trait Ext: sealing::Sealed {
 	/// Returns the price for the specified amount of weight.
 	fn get_weight_price(&self, weight: Weight) -> BalanceOf<Self::T>;
 
+	/// Returns the weight that is left in the current block.
+	///
+	/// The maximum weight of the extrinsic that is currently executing was already registered
+	/// before it was dispatched. Hence it is deducted from the returned value, even the part
+	/// that is refunded once the extrinsic finishes.
+	fn block_weight_remaining(&self) -> Weight;
+
 	/// Get a reference to the schedule used by the current call.
 	fn schedule(&self) -> &Schedule<Self::T>;
 
@@ -296,6 +308,18 @@ pub trait Ext: sealing::Sealed {
 	/// Verify a sr25519 signature.
 	fn sr25519_verify(&self, signature: &[u8; 64], message: &[u8], pub_key: &[u8; 32]) -> bool;
 
+	/// Verify that `proof` proves `key` to hold `value` in the state with the given `root`.
+	///
+	/// `proof` is the set of trie nodes of a [`sp_trie::StorageProof`] as created by recording
+	/// the read of `key`. Compact proofs are not supported.
+	fn state_proof_verify(
+		&self,
+		root: &[u8; 32],
+		key: &[u8],
+		value: &[u8],
+		proof: &[Vec<u8>],
+	) -> bool;
+
 	/// Returns Ethereum address from the ECDSA compressed public key.
 	fn ecdsa_to_eth_address(&self, pk: &[u8; 33]) -> Result<[u8; 20], ()>;
 
@@ -1383,6 +1407,11 @@ where
 		T::Randomness::random(subject)
 	}
 
+	fn vrf_random(&self, subject: &[u8]) -> Option<[u8; 32]> {
+		T::BabeDataGetter::vrf_output()
+			.map(|vrf_output| blake2_256(&[&vrf_output[..], subject].concat()))
+	}
+
 	fn now(&self) -> &MomentOf<T> {
 		&self.timestamp
 	}
@@ -1410,6 +1439,12 @@ where
 		T::WeightPrice::convert(weight)
 	}
 
+	fn block_weight_remaining(&self) -> Weight {
+		<T as frame_system::Config>::BlockWeights::get()
+			.max_block
+			.saturating_sub(frame_system::Pallet::<T>::block_weight().total())
+	}
+
 	fn schedule(&self) -> &Schedule<Self::T> {
 		self.schedule
 	}
@@ -1462,6 +1497,26 @@ where
 		)
 	}
 
+	fn state_proof_verify(
+		&self,
+		root: &[u8; 32],
+		key: &[u8],
+		value: &[u8],
+		proof: &[Vec<u8>],
+	) -> bool {
+		let db = sp_trie::StorageProof::new(proof.iter().cloned()).into_memory_db::<BlakeTwo256>();
+		matches!(
+			sp_trie::read_trie_value::<sp_trie::LayoutV1<BlakeTwo256>, _>(
+				&db,
+				&H256::from(*root),
+				key,
+				None,
+				None,
+			),
+			Ok(Some(found)) if found == value
+		)
+	}
+
 	fn ecdsa_to_eth_address(&self, pk: &[u8; 33]) -> Result<[u8; 20], ()> {
 		ECDSAPublic(*pk).to_eth_address()
 	}
@@ -3798,6 +3853,70 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn state_proof_verify_works() {
+		use sp_runtime::traits::BlakeTwo256;
+		use sp_trie::{
+			recorder::Recorder, LayoutV1, MemoryDB, Trie, TrieDBBuilder, TrieDBMutBuilder, TrieMut,
+		};
+
+		// Build a trie and record the nodes accessed when reading a single key from it.
+		let mut db = MemoryDB::<BlakeTwo256>::default();
+		let mut root = Default::default();
+		{
+			let mut trie =
+				TrieDBMutBuilder::<LayoutV1<BlakeTwo256>>::new(&mut db, &mut root).build();
+			for i in 0u32..64 {
+				trie.insert(&i.to_le_bytes(), &[i as u8; 40]).unwrap();
+			}
+		}
+		let recorder = Recorder::<BlakeTwo256>::default();
+		{
+			let mut trie_recorder = recorder.as_trie_recorder(root);
+			let trie = TrieDBBuilder::<LayoutV1<BlakeTwo256>>::new(&db, &root)
+				.with_recorder(&mut trie_recorder)
+				.build();
+			assert_eq!(trie.get(&7u32.to_le_bytes()).unwrap(), Some(vec![7; 40]));
+		}
+		let proof: Vec<Vec<u8>> = recorder.drain_storage_proof().into_iter_nodes().collect();
+		let root: [u8; 32] = root.into();
+		let key = 7u32.to_le_bytes();
+
+		let bob_ch = MockLoader::insert(Call, move |ctx, _| {
+			assert!(ctx.ext.state_proof_verify(&root, &key, &[7; 40], &proof));
+			// Wrong value.
+			assert!(!ctx.ext.state_proof_verify(&root, &key, &[8; 40], &proof));
+			// Key not covered by the proof.
+			assert!(!ctx.ext.state_proof_verify(&root, &8u32.to_le_bytes(), &[8; 40], &proof));
+			// Wrong root.
+			assert!(!ctx.ext.state_proof_verify(&[0; 32], &key, &[7; 40], &proof));
+			// Incomplete proof.
+			assert!(!ctx.ext.state_proof_verify(&root, &key, &[7; 40], &proof[1..]));
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, bob_ch);
+
+			let contract_origin = Origin::from_account_id(ALICE);
+			let mut storage_meter =
+				storage::meter::Meter::new(&contract_origin, Some(0), 0).unwrap();
+			let result = MockStack::run_call(
+				contract_origin,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				Determinism::Enforced,
+			);
+			assert_matches!(result, Ok(_));
+		});
+	}
+
 	#[test]
 	fn nonce_api_works() {
 		let fail_code = MockLoader::insert(Constructor, |_, _| exec_trapped());
@@ -3896,4 +4015,37 @@ mod tests {
 			assert_matches!(result, Ok(_));
 		});
 	}
+
+	#[test]
+	fn vrf_randomness_works() {
+		let subject = b"nice subject".as_ref();
+		let code_hash = MockLoader::insert(Call, move |ctx, _| {
+			let vrf_output = <Test as Config>::BabeDataGetter::vrf_output().unwrap();
+			let expected = blake2_256(&[&vrf_output[..], subject].concat());
+			assert_eq!(ctx.ext.vrf_random(subject), Some(expected));
+			assert_ne!(ctx.ext.vrf_random(b"other subject"), Some(expected));
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, code_hash);
+
+			let contract_origin = Origin::from_account_id(ALICE);
+			let mut storage_meter =
+				storage::meter::Meter::new(&contract_origin, Some(0), 0).unwrap();
+			let result = MockStack::run_call(
+				contract_origin,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				Determinism::Enforced,
+			);
+			assert_matches!(result, Ok(_));
+		});
+	}
 }
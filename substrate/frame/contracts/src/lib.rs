@@ -91,6 +91,7 @@ mod address;
 mod benchmarking;
 mod exec;
 mod gas;
+mod randomness;
 mod schedule;
 mod storage;
 mod wasm;
@@ -145,6 +146,7 @@ pub use crate::{
 	exec::Frame,
 	migration::{MigrateSequence, Migration, NoopMigration},
 	pallet::*,
+	randomness::BabeDataGetter,
 	schedule::{HostFnWeights, InstructionWeights, Limits, Schedule},
 	wasm::Determinism,
 };
@@ -244,6 +246,12 @@ pub mod pallet {
 		/// to supply a dummy implementation for this type (because it is never used).
 		type Randomness: Randomness<Self::Hash, BlockNumberFor<Self>>;
 
+		/// Supplies the VRF output used by the `vrf_random` host function.
+		///
+		/// Use `()` if the runtime does not use BABE. Contracts calling `vrf_random` will then
+		/// receive `ReturnCode::VrfRandomnessUnavailable`.
+		type BabeDataGetter: BabeDataGetter;
+
 		/// The fungible in which fees are paid and contract balances are held.
 		type Currency: Inspect<Self::AccountId>
 			+ Mutate<Self::AccountId>
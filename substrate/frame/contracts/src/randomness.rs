@@ -0,0 +1,37 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sources of randomness that are exposed to contracts.
+
+/// Provides access to the VRF output of the current BABE epoch.
+///
+/// This is used by the `vrf_random` host function in order to supply contracts with randomness.
+/// The VRF output is the same for the whole epoch and is public before the epoch starts, so the
+/// values derived from it are predictable.
+///
+/// A runtime using `pallet-babe` would usually return `pallet_babe::Pallet::randomness()`.
+/// The `()` implementation returns `None` which makes `vrf_random` unavailable to contracts.
+pub trait BabeDataGetter {
+	/// The VRF output of the current epoch, or `None` if it is not available.
+	fn vrf_output() -> Option<[u8; 32]>;
+}
+
+impl BabeDataGetter for () {
+	fn vrf_output() -> Option<[u8; 32]> {
+		None
+	}
+}
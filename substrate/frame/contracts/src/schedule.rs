@@ -115,6 +115,12 @@ pub struct Limits {
 	/// The maximum size of a storage value and event payload in bytes.
 	pub payload_len: u32,
 
+	/// The maximum length in bytes of a storage key passed to `state_proof_verify`.
+	///
+	/// The key belongs to another chain, so it is not bound by [`Config::MaxStorageKeyLen`]. Keys
+	/// of maps with several hashed keys easily exceed that.
+	pub state_proof_key_len: u32,
+
 	/// The maximum node runtime memory. This is for integrity checks only and does not affect the
 	/// real setting.
 	pub runtime_memory: u32,
@@ -170,6 +176,12 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `seal_gas_left`.
 	pub gas_left: Weight,
 
+	/// Weight of calling `block_weight_remaining`.
+	pub block_weight_remaining: Weight,
+
+	/// Weight of calling `event_topics_count_limit`.
+	pub event_topics_count_limit: Weight,
+
 	/// Weight of calling `seal_balance`.
 	pub balance: Weight,
 
@@ -206,6 +218,9 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `seal_random`.
 	pub random: Weight,
 
+	/// Weight of calling `vrf_random`.
+	pub vrf_random: Weight,
+
 	/// Weight of calling `seal_reposit_event`.
 	pub deposit_event: Weight,
 
@@ -257,6 +272,12 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight per byte of an item received via `seal_take_storage`.
 	pub take_storage_per_byte: Weight,
 
+	/// Weight of calling `storage_batch`, excluding the operations themselves.
+	pub storage_batch: Weight,
+
+	/// Weight per byte of the encoded operations passed to `storage_batch`.
+	pub storage_batch_per_byte: Weight,
+
 	/// Weight of calling `seal_transfer`.
 	pub transfer: Weight,
 
@@ -320,6 +341,15 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight per byte of calling `sr25519_verify`.
 	pub sr25519_verify_per_byte: Weight,
 
+	/// Weight of calling `state_proof_verify`.
+	pub state_proof_verify: Weight,
+
+	/// Weight per proof node of calling `state_proof_verify`.
+	pub state_proof_verify_per_node: Weight,
+
+	/// Weight per input byte of calling `state_proof_verify`.
+	pub state_proof_verify_per_byte: Weight,
+
 	/// Weight of calling `reentrance_count`.
 	pub reentrance_count: Weight,
 
@@ -390,6 +420,7 @@ impl Default for Limits {
 			br_table_size: 256,
 			subject_len: 32,
 			payload_len: 16 * 1024,
+			state_proof_key_len: 1024,
 			runtime_memory: 1024 * 1024 * 128,
 		}
 	}
@@ -414,6 +445,8 @@ impl<T: Config> Default for HostFnWeights<T> {
 			caller_is_root: cost!(seal_caller_is_root),
 			address: cost!(seal_address),
 			gas_left: cost!(seal_gas_left),
+			block_weight_remaining: cost!(seal_block_weight_remaining),
+			event_topics_count_limit: cost!(seal_event_topics_count_limit),
 			balance: cost!(seal_balance),
 			value_transferred: cost!(seal_value_transferred),
 			minimum_balance: cost!(seal_minimum_balance),
@@ -426,6 +459,7 @@ impl<T: Config> Default for HostFnWeights<T> {
 			return_per_byte: cost!(seal_return_per_byte),
 			terminate: cost!(seal_terminate),
 			random: cost!(seal_random),
+			vrf_random: cost!(seal_vrf_random),
 			deposit_event: cost!(seal_deposit_event),
 			deposit_event_per_topic: cost_args!(seal_deposit_event_per_topic_and_byte, 1, 0),
 			deposit_event_per_byte: cost_args!(seal_deposit_event_per_topic_and_byte, 0, 1),
@@ -443,6 +477,8 @@ impl<T: Config> Default for HostFnWeights<T> {
 			get_storage_per_byte: cost!(seal_get_storage_per_byte),
 			take_storage: cost!(seal_take_storage),
 			take_storage_per_byte: cost!(seal_take_storage_per_byte),
+			storage_batch: cost!(seal_storage_batch),
+			storage_batch_per_byte: cost!(seal_storage_batch_per_byte),
 			transfer: cost!(seal_transfer),
 			call: cost!(seal_call),
 			delegate_call: cost!(seal_delegate_call),
@@ -478,6 +514,9 @@ impl<T: Config> Default for HostFnWeights<T> {
 			ecdsa_recover: cost!(seal_ecdsa_recover),
 			sr25519_verify: cost!(seal_sr25519_verify),
 			sr25519_verify_per_byte: cost!(seal_sr25519_verify_per_byte),
+			state_proof_verify: cost!(seal_state_proof_verify),
+			state_proof_verify_per_node: cost!(seal_state_proof_verify_per_node),
+			state_proof_verify_per_byte: cost!(seal_state_proof_verify_per_byte),
 			ecdsa_to_eth_address: cost!(seal_ecdsa_to_eth_address),
 			reentrance_count: cost!(seal_reentrance_count),
 			account_reentrance_count: cost!(seal_account_reentrance_count),
@@ -457,9 +457,17 @@ parameter_types! {
 	pub static UnstableInterface: bool = true;
 }
 
+pub struct TestBabeDataGetter;
+impl crate::BabeDataGetter for TestBabeDataGetter {
+	fn vrf_output() -> Option<[u8; 32]> {
+		Some([7u8; 32])
+	}
+}
+
 impl Config for Test {
 	type Time = Timestamp;
 	type Randomness = Randomness;
+	type BabeDataGetter = TestBabeDataGetter;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -525,6 +525,7 @@ mod tests {
 		debug_buffer: Vec<u8>,
 		ecdsa_recover: RefCell<Vec<([u8; 65], [u8; 32])>>,
 		sr25519_verify: RefCell<Vec<([u8; 64], Vec<u8>, [u8; 32])>>,
+		state_proof_verify: RefCell<Vec<([u8; 32], Vec<u8>, Vec<u8>, Vec<Vec<u8>>)>>,
 		code_hashes: Vec<CodeHash<Test>>,
 		caller: Origin<Test>,
 		delegate_dependencies: RefCell<HashSet<CodeHash<Test>>>,
@@ -553,6 +554,7 @@ mod tests {
 				ecdsa_recover: Default::default(),
 				caller: Default::default(),
 				sr25519_verify: Default::default(),
+				state_proof_verify: Default::default(),
 				delegate_dependencies: Default::default(),
 			}
 		}
@@ -676,6 +678,9 @@ mod tests {
 		fn random(&self, subject: &[u8]) -> (SeedOf<Self::T>, BlockNumberFor<Self::T>) {
 			(H256::from_slice(subject), 42)
 		}
+		fn vrf_random(&self, subject: &[u8]) -> Option<[u8; 32]> {
+			Some(H256::from_slice(subject).0)
+		}
 		fn deposit_event(&mut self, topics: Vec<H256>, data: Vec<u8>) {
 			self.events.push((topics, data))
 		}
@@ -692,6 +697,9 @@ mod tests {
 					BalanceOf::<Self::T>::from(103_u32).saturating_mul(weight.proof_size()),
 				)
 		}
+		fn block_weight_remaining(&self) -> Weight {
+			Weight::from_parts(500_000_000, 64 * 1024)
+		}
 		fn schedule(&self) -> &Schedule<Self::T> {
 			&self.schedule
 		}
@@ -725,6 +733,21 @@ mod tests {
 			self.sr25519_verify.borrow_mut().push((*signature, message.to_vec(), *pub_key));
 			true
 		}
+		fn state_proof_verify(
+			&self,
+			root: &[u8; 32],
+			key: &[u8],
+			value: &[u8],
+			proof: &[Vec<u8>],
+		) -> bool {
+			self.state_proof_verify.borrow_mut().push((
+				*root,
+				key.to_vec(),
+				value.to_vec(),
+				proof.to_vec(),
+			));
+			true
+		}
 		fn contract_info(&mut self) -> &mut crate::ContractInfo<Self::T> {
 			unimplemented!()
 		}
@@ -1493,6 +1516,89 @@ mod tests {
 		assert_eq!(mock_ext.sr25519_verify.into_inner(), [([1; 64], [1; 16].to_vec(), [1; 32])]);
 	}
 
+	#[test]
+	fn state_proof_verify_works() {
+		const CODE: &str = r#"
+(module
+	(import "seal0" "state_proof_verify"
+		(func $state_proof_verify (param i32 i32 i32 i32 i32 i32 i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+	(func (export "call")
+		(drop
+			(call $state_proof_verify
+				(i32.const 0) ;; Pointer to the state root.
+				(i32.const 32) ;; Pointer to the key.
+				(i32.const 4) ;; Length of the key.
+				(i32.const 36) ;; Pointer to the value.
+				(i32.const 2) ;; Length of the value.
+				(i32.const 38) ;; Pointer to the proof.
+				(i32.const 6) ;; Length of the proof.
+			)
+		)
+	)
+	(func (export "deploy"))
+
+	;; State root (32 bytes)
+	(data (i32.const 0)
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+	)
+
+	;; Key (4 bytes)
+	(data (i32.const 32) "\02\02\02\02")
+
+	;; Value (2 bytes)
+	(data (i32.const 36) "\03\03")
+
+	;; Proof encoded as `Vec<Vec<u8>>` with two nodes (6 bytes)
+	(data (i32.const 38) "\08\04\04\08\05\05")
+)
+"#;
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(&CODE, vec![], &mut mock_ext));
+		assert_eq!(
+			mock_ext.state_proof_verify.into_inner(),
+			[([1; 32], vec![2; 4], vec![3; 2], vec![vec![4], vec![5, 5]])]
+		);
+	}
+
+	#[test]
+	fn state_proof_verify_accepts_keys_longer_than_max_storage_key_len() {
+		const CODE: &str = r#"
+(module
+	(import "seal0" "state_proof_verify"
+		(func $state_proof_verify (param i32 i32 i32 i32 i32 i32 i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+	(func (export "call")
+		(drop
+			(call $state_proof_verify
+				(i32.const 0) ;; Pointer to the state root.
+				(i32.const 32) ;; Pointer to the key.
+				(i32.const 200) ;; Length of the key.
+				(i32.const 232) ;; Pointer to the value.
+				(i32.const 0) ;; Length of the value.
+				(i32.const 232) ;; Pointer to the proof.
+				(i32.const 1) ;; Length of the proof.
+			)
+		)
+	)
+	(func (export "deploy"))
+
+	;; The state root and the key are left zeroed.
+
+	;; Proof encoded as an empty `Vec<Vec<u8>>` (1 byte)
+	(data (i32.const 232) "\00")
+)
+"#;
+		assert!(200 > <Test as Config>::MaxStorageKeyLen::get());
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(&CODE, vec![], &mut mock_ext));
+		assert_eq!(
+			mock_ext.state_proof_verify.into_inner(),
+			[([0; 32], vec![0; 200], vec![], vec![])]
+		);
+	}
+
 	const CODE_GET_STORAGE: &str = r#"
 (module
 	(import "seal0" "seal_get_storage" (func $seal_get_storage (param i32 i32 i32) (result i32)))
@@ -1821,6 +1927,31 @@ mod tests {
 		assert!(weight_left.all_gt(actual_left), "gas_left must be greater than final");
 	}
 
+	#[test]
+	fn block_weight_remaining_works() {
+		const CODE: &str = r#"
+(module
+	(import "seal0" "block_weight_remaining" (func $block_weight_remaining (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; size of our buffer is 32 bytes
+	(data (i32.const 32) "\20")
+
+	(func (export "call")
+		(call $block_weight_remaining (i32.const 0) (i32.const 32))
+		(call $seal_return (i32.const 0) (i32.const 0) (i32.load (i32.const 32)))
+	)
+	(func (export "deploy"))
+)
+"#;
+		let output = execute(CODE, vec![], MockExt::default()).unwrap();
+		assert_eq!(
+			Weight::decode(&mut &*output.data).unwrap(),
+			Weight::from_parts(500_000_000, 64 * 1024)
+		);
+	}
+
 	/// Test that [`frame_support::weights::OldWeight`] en/decodes the same as our
 	/// [`crate::OldWeight`].
 	#[test]
@@ -2183,6 +2314,68 @@ mod tests {
 		);
 	}
 
+	const CODE_VRF_RANDOM: &str = r#"
+(module
+	(import "seal0" "vrf_random" (func $vrf_random (param i32 i32 i32) (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0,32) is reserved for the result of the VRF randomness.
+
+	;; the subject used for the randomness. [32,64)
+	(data (i32.const 32)
+		"\00\01\02\03\04\05\06\07\08\09\0A\0B\0C\0D\0E\0F"
+		"\00\01\02\03\04\05\06\07\08\09\0A\0B\0C\0D\0E\0F"
+	)
+
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+
+	(func (export "call")
+		;; assert return code is Success
+		(call $assert
+			(i32.eqz
+				(call $vrf_random
+					(i32.const 32) ;; Pointer in memory to the start of the subject buffer
+					(i32.const 32) ;; The subject buffer's length
+					(i32.const 0) ;; Pointer to the output buffer
+				)
+			)
+		)
+
+		;; return the random data
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 0)
+			(i32.const 32)
+		)
+	)
+	(func (export "deploy"))
+)
+"#;
+
+	#[test]
+	fn vrf_random() {
+		let output = execute(CODE_VRF_RANDOM, vec![], MockExt::default()).unwrap();
+
+		// The mock ext just returns the same data that was passed as the subject.
+		assert_eq!(
+			output,
+			ExecReturnValue {
+				flags: ReturnFlags::empty(),
+				data: array_bytes::hex_into_unchecked(
+					"000102030405060708090A0B0C0D0E0F000102030405060708090A0B0C0D0E0F"
+				)
+			},
+		);
+	}
+
 	const CODE_DEPOSIT_EVENT: &str = r#"
 (module
 	(import "seal0" "seal_deposit_event" (func $seal_deposit_event (param i32 i32 i32 i32)))
@@ -2307,6 +2500,29 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn event_topics_count_limit_works() {
+		const CODE: &str = r#"
+(module
+	(import "seal0" "event_topics_count_limit" (func $event_topics_count_limit (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+	(func (export "call")
+		(i32.store
+			(i32.const 0)
+			(call $event_topics_count_limit)
+		)
+		(call $seal_return (i32.const 0) (i32.const 0) (i32.const 4))
+	)
+	(func (export "deploy"))
+)
+"#;
+		let mut ext = MockExt::default();
+		let limit = ext.schedule().limits.event_topics;
+		let output = execute(CODE, vec![], &mut ext).unwrap();
+		assert_eq!(output, ExecReturnValue { flags: ReturnFlags::empty(), data: limit.encode() });
+	}
+
 	/// calls `seal_block_number` compares the result with the constant 121.
 	const CODE_BLOCK_NUMBER: &str = r#"
 (module
@@ -2936,6 +3152,73 @@ mod tests {
 		assert_eq!(&result.data[4..], &[0u8; 0]);
 	}
 
+	#[test]
+	fn storage_batch_works() {
+		const CODE: &str = r#"
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "seal0" "storage_batch" (func $storage_batch (param i32 i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) size of input buffer
+	;; 4k in little endian
+	(data (i32.const 0) "\00\10")
+
+	;; [4, 8) size of output buffer
+	;; 4k in little endian
+	(data (i32.const 4) "\00\10")
+
+	;; [8, 4104) input buffer
+	;; [4104, 8200) output buffer
+
+	(func (export "call")
+		;; Receive the encoded operations
+		(call $seal_input
+			(i32.const 8)	;; Pointer to the input buffer
+			(i32.const 0)	;; Size of the input buffer
+		)
+		(call $storage_batch
+			(i32.const 8)			;; ops_ptr
+			(i32.load (i32.const 0))	;; ops_len
+			(i32.const 4104)		;; Pointer to the output buffer
+			(i32.const 4)			;; Pointer to the size of the buffer
+		)
+		(call $seal_return
+			(i32.const 0)			;; flags
+			(i32.const 4104)		;; output buffer ptr
+			(i32.load (i32.const 4))	;; output buffer length
+		)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+
+		let mut ext = MockExt::default();
+
+		ext.set_storage(
+			&Key::<Test>::try_from_var([1u8; 64].to_vec()).unwrap(),
+			Some(vec![42u8]),
+			false,
+		)
+		.unwrap();
+
+		let ops: Vec<(Vec<u8>, Option<Vec<u8>>)> = vec![
+			([1u8; 64].to_vec(), None),
+			([2u8; 19].to_vec(), Some(vec![7u8, 8])),
+			([2u8; 19].to_vec(), None),
+			([3u8; 32].to_vec(), None),
+		];
+		let result = execute(CODE, ops.encode(), &mut ext).unwrap();
+
+		assert_eq!(
+			<Vec<Option<Vec<u8>>>>::decode(&mut &result.data[..]).unwrap(),
+			vec![Some(vec![42u8]), None, Some(vec![7u8, 8]), None],
+		);
+		assert_eq!(ext.storage.get(&[2u8; 19].to_vec()).unwrap(), &[7u8, 8]);
+	}
+
 	#[test]
 	fn is_contract_works() {
 		const CODE_IS_CONTRACT: &str = r#"
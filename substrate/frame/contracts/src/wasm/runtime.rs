@@ -113,6 +113,10 @@ pub enum ReturnCode {
 	EcdsaRecoverFailed = 11,
 	/// sr25519 signature verification failed.
 	Sr25519VerifyFailed = 12,
+	/// No VRF output is available to derive randomness from.
+	VrfRandomnessUnavailable = 13,
+	/// The supplied storage proof does not prove the key value pair against the root.
+	StateProofVerifyFailed = 14,
 }
 
 impl From<ExecReturnValue> for ReturnCode {
@@ -216,6 +220,8 @@ pub enum RuntimeCosts {
 	Terminate,
 	/// Weight of calling `seal_random`. It includes the weight for copying the subject.
 	Random,
+	/// Weight of calling `vrf_random`. It includes the weight for copying the subject.
+	VrfRandom,
 	/// Weight of calling `seal_deposit_event` with the given number of topics and event size.
 	DepositEvent { num_topic: u32, len: u32 },
 	/// Weight of calling `seal_debug_message` per byte of passed message.
@@ -230,6 +236,8 @@ pub enum RuntimeCosts {
 	GetStorage(u32),
 	/// Weight of calling `seal_take_storage` for the given size.
 	TakeStorage(u32),
+	/// Weight of calling `storage_batch` for the given size of the encoded operations.
+	StorageBatch(u32),
 	/// Weight of calling `seal_transfer`.
 	Transfer,
 	/// Base weight of calling `seal_call`.
@@ -274,6 +282,12 @@ pub enum RuntimeCosts {
 	AddDelegateDependency,
 	/// Weight of calling `remove_delegate_dependency`
 	RemoveDelegateDependency,
+	/// Weight of calling `event_topics_count_limit`
+	EventTopicsCountLimit,
+	/// Weight of calling `block_weight_remaining`
+	BlockWeightRemaining,
+	/// Weight of calling `state_proof_verify` for the given number of proof nodes and input size.
+	StateProofVerify { num_nodes: u32, len: u32 },
 }
 
 impl RuntimeCosts {
@@ -300,6 +314,7 @@ impl RuntimeCosts {
 			Return(len) => s.r#return.saturating_add(s.return_per_byte.saturating_mul(len.into())),
 			Terminate => s.terminate,
 			Random => s.random,
+			VrfRandom => s.vrf_random,
 			DepositEvent { num_topic, len } => s
 				.deposit_event
 				.saturating_add(s.deposit_event_per_topic.saturating_mul(num_topic.into()))
@@ -322,6 +337,9 @@ impl RuntimeCosts {
 			TakeStorage(len) => s
 				.take_storage
 				.saturating_add(s.take_storage_per_byte.saturating_mul(len.into())),
+			StorageBatch(len) => s
+				.storage_batch
+				.saturating_add(s.storage_batch_per_byte.saturating_mul(len.into())),
 			Transfer => s.transfer,
 			CallBase => s.call,
 			DelegateCallBase => s.delegate_call,
@@ -357,6 +375,12 @@ impl RuntimeCosts {
 			InstantationNonce => s.instantiation_nonce,
 			AddDelegateDependency => s.add_delegate_dependency,
 			RemoveDelegateDependency => s.remove_delegate_dependency,
+			EventTopicsCountLimit => s.event_topics_count_limit,
+			BlockWeightRemaining => s.block_weight_remaining,
+			StateProofVerify { num_nodes, len } => s
+				.state_proof_verify
+				.saturating_add(s.state_proof_verify_per_node.saturating_mul(num_nodes.into()))
+				.saturating_add(s.state_proof_verify_per_byte.saturating_mul(len.into())),
 		};
 		RuntimeToken {
 			#[cfg(test)]
@@ -1261,6 +1285,95 @@ pub mod env {
 		}
 	}
 
+	/// Apply a batch of storage reads and writes in a single call.
+	///
+	/// The operations are applied in order. Key and value lengths are subject to the same limits
+	/// as [`get_storage`][`Self::get_storage`] and [`set_storage`][`Self::set_storage`]. Each
+	/// operation is charged like a separate call to one of those functions.
+	///
+	/// # Parameters
+	///
+	/// - `ops_ptr`: pointer into the linear memory where the SCALE encoded operations are placed.
+	///   They are expected as `Vec<(Vec<u8>, Option<Vec<u8>>)>` where each entry is a key together
+	///   with the value to write to it, or `None` to read the value at the key.
+	/// - `ops_len`: the length of the encoded operations in bytes.
+	/// - `out_ptr`: pointer to the linear memory where the results are written to.
+	/// - `out_len_ptr`: in-out pointer into linear memory where the buffer length is read from and
+	///   the results length is written to.
+	///
+	/// # Return Value
+	///
+	/// The results are written as a SCALE encoded `Vec<Option<Vec<u8>>>` with one entry per
+	/// operation. Reads yield the value stored at the key if any, writes always yield `None`.
+	///
+	/// # Traps
+	///
+	/// - If the operations can't be decoded or a key or value exceeds its limit.
+	/// - If the output buffer is too small for the results.
+	///
+	/// Trapping reverts the whole call, including the writes of this batch that were already
+	/// applied.
+	#[unstable]
+	fn storage_batch(
+		ctx: _,
+		memory: _,
+		ops_ptr: u32,
+		ops_len: u32,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::StorageBatch(ops_len))?;
+		let ops: Vec<(Vec<u8>, Option<Vec<u8>>)> =
+			ctx.read_sandbox_memory_as_unbounded(memory, ops_ptr, ops_len)?;
+		let max_size = ctx.ext.max_value_size();
+		let mut results = Vec::with_capacity(ops.len());
+		for (key, value) in ops {
+			ensure!(
+				key.len() as u32 <= <<E as Ext>::T as Config>::MaxStorageKeyLen::get(),
+				Error::<E::T>::DecodingFailed
+			);
+			let key = Key::<E::T>::try_from_var(key).map_err(|_| Error::<E::T>::DecodingFailed)?;
+			match value {
+				Some(value) => {
+					let value_len = value.len() as u32;
+					let charged = ctx.charge_gas(RuntimeCosts::SetStorage {
+						new_bytes: value_len,
+						old_bytes: max_size,
+					})?;
+					if value_len > max_size {
+						return Err(Error::<E::T>::ValueTooLarge.into())
+					}
+					let outcome = ctx.ext.set_storage(&key, Some(value), false)?;
+					ctx.adjust_gas(
+						charged,
+						RuntimeCosts::SetStorage {
+							new_bytes: value_len,
+							old_bytes: outcome.old_len(),
+						},
+					);
+					results.push(None);
+				},
+				None => {
+					let charged = ctx.charge_gas(RuntimeCosts::GetStorage(max_size))?;
+					let value = ctx.ext.get_storage(&key);
+					ctx.adjust_gas(
+						charged,
+						RuntimeCosts::GetStorage(value.as_ref().map_or(0, |v| v.len() as u32)),
+					);
+					results.push(value);
+				},
+			}
+		}
+		Ok(ctx.write_sandbox_output(
+			memory,
+			out_ptr,
+			out_len_ptr,
+			&results.encode(),
+			false,
+			|len| Some(RuntimeCosts::CopyToContract(len)),
+		)?)
+	}
+
 	/// Transfer some value to another account.
 	///
 	/// # Parameters
@@ -2010,6 +2123,38 @@ pub mod env {
 		)?)
 	}
 
+	/// Stores the weight that is left in the current block into the supplied buffer.
+	///
+	/// The value is stored to linear memory at the address pointed to by `out_ptr`.
+	/// `out_len_ptr` must point to a u32 value that describes the available space at
+	/// `out_ptr`. This call overwrites it with the size of the value. If the available
+	/// space at `out_ptr` is less than the size of the value a trap is triggered.
+	///
+	/// The maximum weight of the extrinsic that is currently executing was already registered
+	/// before it was dispatched. Hence it is deducted from the returned value, even the part that
+	/// is refunded once the extrinsic finishes. Use [`gas_left`][`Self::gas_left`] to learn about
+	/// the weight available to the current call.
+	///
+	/// The data is encoded as Weight.
+	#[unstable]
+	fn block_weight_remaining(
+		ctx: _,
+		memory: _,
+		out_ptr: u32,
+		out_len_ptr: u32,
+	) -> Result<(), TrapReason> {
+		ctx.charge_gas(RuntimeCosts::BlockWeightRemaining)?;
+		let remaining = &ctx.ext.block_weight_remaining().encode();
+		Ok(ctx.write_sandbox_output(
+			memory,
+			out_ptr,
+			out_len_ptr,
+			remaining,
+			false,
+			already_charged,
+		)?)
+	}
+
 	/// Stores the *free* balance of the current account into the supplied buffer.
 	///
 	/// The value is stored to linear memory at the address pointed to by `out_ptr`.
@@ -2137,6 +2282,49 @@ pub mod env {
 		)?)
 	}
 
+	/// Stores a random value derived from the current epoch's VRF output and the given subject
+	/// into the supplied buffer.
+	///
+	/// The value is computed as `blake2_256(vrf_output ++ subject)`.
+	///
+	/// # Note
+	///
+	/// The VRF output is the same for the whole epoch and is public before the epoch starts.
+	/// Anyone can therefore compute the returned value in advance for any subject. Do not use it
+	/// where the outcome must be unpredictable, e.g. for lotteries or games of chance.
+	///
+	/// # Parameters
+	///
+	/// - `subject_ptr`: the pointer into the linear memory where the subject is placed.
+	/// - `subject_len`: the length of the subject in bytes.
+	/// - `out_ptr`: the pointer into the linear memory where the output data is placed. The buffer
+	///   should be 32 bytes. The function will write the result directly into this buffer.
+	///
+	/// # Errors
+	///
+	/// - `ReturnCode::VrfRandomnessUnavailable`
+	#[unstable]
+	fn vrf_random(
+		ctx: _,
+		memory: _,
+		subject_ptr: u32,
+		subject_len: u32,
+		out_ptr: u32,
+	) -> Result<ReturnCode, TrapReason> {
+		ctx.charge_gas(RuntimeCosts::VrfRandom)?;
+		if subject_len > ctx.ext.schedule().limits.subject_len {
+			return Err(Error::<E::T>::RandomSubjectTooLong.into())
+		}
+		let subject_buf = ctx.read_sandbox_memory(memory, subject_ptr, subject_len)?;
+		match ctx.ext.vrf_random(&subject_buf) {
+			Some(random) => {
+				ctx.write_sandbox_memory(memory, out_ptr, &random)?;
+				Ok(ReturnCode::Success)
+			},
+			None => Ok(ReturnCode::VrfRandomnessUnavailable),
+		}
+	}
+
 	/// Load the latest block timestamp into the supplied buffer
 	///
 	/// The value is stored to linear memory at the address pointed to by `out_ptr`.
@@ -2349,6 +2537,16 @@ pub mod env {
 		Ok(())
 	}
 
+	/// Returns the maximum number of topics that can be passed to
+	/// [`deposit_event`][`Self::deposit_event`].
+	///
+	/// Depositing an event with more topics traps the caller.
+	#[unstable]
+	fn event_topics_count_limit(ctx: _, _memory: _) -> Result<u32, TrapReason> {
+		ctx.charge_gas(RuntimeCosts::EventTopicsCountLimit)?;
+		Ok(ctx.ext.schedule().limits.event_topics)
+	}
+
 	/// Stores the current block number of the current contract into the supplied buffer.
 	///
 	/// The value is stored to linear memory at the address pointed to by `out_ptr`.
@@ -2712,6 +2910,64 @@ pub mod env {
 		}
 	}
 
+	/// Verify a storage proof against a state root.
+	///
+	/// The proof is checked for a trie using `BlakeTwo256` as hasher, which is what Substrate based
+	/// chains use for their state.
+	///
+	/// # Parameters
+	///
+	/// - `root_ptr`: the pointer into the linear memory where the state root is placed. Should be a
+	///   value of 32 bytes.
+	/// - `key_ptr`: the pointer into the linear memory where the storage key is placed.
+	/// - `key_len`: the length of the storage key in bytes. At most `Limits::state_proof_key_len`.
+	/// - `value_ptr`: the pointer into the linear memory where the expected value is placed.
+	/// - `value_len`: the length of the expected value in bytes.
+	/// - `proof_ptr`: the pointer into the linear memory where the proof is placed. It is expected
+	///   to be the SCALE encoded `Vec<Vec<u8>>` of the trie nodes of a storage proof, as returned
+	///   by the `state_getReadProof` RPC. Compact proofs are not supported.
+	/// - `proof_len`: the length of the encoded proof in bytes.
+	///
+	/// # Errors
+	///
+	/// - `ReturnCode::StateProofVerifyFailed`
+	#[unstable]
+	fn state_proof_verify(
+		ctx: _,
+		memory: _,
+		root_ptr: u32,
+		key_ptr: u32,
+		key_len: u32,
+		value_ptr: u32,
+		value_len: u32,
+		proof_ptr: u32,
+		proof_len: u32,
+	) -> Result<ReturnCode, TrapReason> {
+		ctx.charge_gas(RuntimeCosts::CopyFromContract(proof_len))?;
+		let proof: Vec<Vec<u8>> =
+			ctx.read_sandbox_memory_as_unbounded(memory, proof_ptr, proof_len)?;
+		ctx.charge_gas(RuntimeCosts::StateProofVerify {
+			num_nodes: proof.len() as u32,
+			len: proof_len.saturating_add(key_len).saturating_add(value_len),
+		})?;
+		ensure!(
+			key_len <= ctx.ext.schedule().limits.state_proof_key_len,
+			Error::<E::T>::DecodingFailed
+		);
+		ensure!(value_len <= ctx.ext.max_value_size(), Error::<E::T>::ValueTooLarge);
+
+		let mut root: [u8; 32] = [0; 32];
+		ctx.read_sandbox_memory_into_buf(memory, root_ptr, &mut root)?;
+		let key = ctx.read_sandbox_memory(memory, key_ptr, key_len)?;
+		let value = ctx.read_sandbox_memory(memory, value_ptr, value_len)?;
+
+		if ctx.ext.state_proof_verify(&root, &key, &value, &proof) {
+			Ok(ReturnCode::Success)
+		} else {
+			Ok(ReturnCode::StateProofVerifyFailed)
+		}
+	}
+
 	/// Replace the contract code at the specified address with new code.
 	///
 	/// # Note
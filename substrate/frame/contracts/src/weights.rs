@@ -78,6 +78,8 @@ pub trait WeightInfo {
 	fn seal_caller_is_root(r: u32, ) -> Weight;
 	fn seal_address(r: u32, ) -> Weight;
 	fn seal_gas_left(r: u32, ) -> Weight;
+	fn seal_block_weight_remaining(r: u32, ) -> Weight;
+	fn seal_event_topics_count_limit(r: u32, ) -> Weight;
 	fn seal_balance(r: u32, ) -> Weight;
 	fn seal_value_transferred(r: u32, ) -> Weight;
 	fn seal_minimum_balance(r: u32, ) -> Weight;
@@ -90,6 +92,7 @@ pub trait WeightInfo {
 	fn seal_return_per_byte(n: u32, ) -> Weight;
 	fn seal_terminate(r: u32, ) -> Weight;
 	fn seal_random(r: u32, ) -> Weight;
+	fn seal_vrf_random(r: u32, ) -> Weight;
 	fn seal_deposit_event(r: u32, ) -> Weight;
 	fn seal_deposit_event_per_topic_and_byte(t: u32, n: u32, ) -> Weight;
 	fn seal_debug_message(r: u32, ) -> Weight;
@@ -105,6 +108,8 @@ pub trait WeightInfo {
 	fn seal_contains_storage_per_byte(n: u32, ) -> Weight;
 	fn seal_take_storage(r: u32, ) -> Weight;
 	fn seal_take_storage_per_byte(n: u32, ) -> Weight;
+	fn seal_storage_batch(r: u32, ) -> Weight;
+	fn seal_storage_batch_per_byte(n: u32, ) -> Weight;
 	fn seal_transfer(r: u32, ) -> Weight;
 	fn seal_call(r: u32, ) -> Weight;
 	fn seal_delegate_call(r: u32, ) -> Weight;
@@ -121,6 +126,9 @@ pub trait WeightInfo {
 	fn seal_hash_blake2_128_per_byte(n: u32, ) -> Weight;
 	fn seal_sr25519_verify_per_byte(n: u32, ) -> Weight;
 	fn seal_sr25519_verify(r: u32, ) -> Weight;
+	fn seal_state_proof_verify(r: u32, ) -> Weight;
+	fn seal_state_proof_verify_per_node(n: u32, ) -> Weight;
+	fn seal_state_proof_verify_per_byte(n: u32, ) -> Weight;
 	fn seal_ecdsa_recover(r: u32, ) -> Weight;
 	fn seal_ecdsa_to_eth_address(r: u32, ) -> Weight;
 	fn seal_set_code_hash(r: u32, ) -> Weight;
@@ -720,6 +728,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 6).saturating_mul(r.into()))
 	}
+	/// Estimate, pending a run of the `seal_block_weight_remaining` benchmark: like
+	/// `seal_block_number` it returns a value without touching non-whitelisted storage.
+	fn seal_block_weight_remaining(r: u32, ) -> Weight {
+		Self::seal_block_number(r)
+	}
+	/// Estimate, pending a run of the `seal_event_topics_count_limit` benchmark: like
+	/// `seal_caller_is_root` it returns a constant without any storage access.
+	fn seal_event_topics_count_limit(r: u32, ) -> Weight {
+		Self::seal_caller_is_root(r)
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:2 w:0)
@@ -1052,6 +1070,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 10).saturating_mul(r.into()))
 	}
+	/// Estimate, pending a run of the `seal_vrf_random` benchmark: like `seal_random` it reads
+	/// one randomness value from storage and hashes it with a subject of bounded length.
+	fn seal_vrf_random(r: u32, ) -> Weight {
+		Self::seal_random(r)
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:0)
@@ -1336,6 +1359,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
 	}
+	/// Upper bound, pending a run of the `seal_storage_batch` benchmark. Every operation is charged
+	/// separately, so the call overhead is below the cost of a `seal_get_storage` call.
+	fn seal_storage_batch(r: u32, ) -> Weight {
+		Self::seal_get_storage(r)
+	}
+	/// Upper bound, pending a run of the `seal_storage_batch_per_byte` benchmark. Decoding the
+	/// operations costs less per byte than writing them to storage.
+	fn seal_storage_batch_per_byte(n: u32, ) -> Weight {
+		Self::seal_set_storage_per_new_byte(n)
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:1602 w:1601)
@@ -1791,6 +1824,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 112).saturating_mul(r.into()))
 	}
+	/// Estimate, pending a run of the `seal_state_proof_verify` benchmark: a verification
+	/// hashes the nodes of a small proof, which costs less than an sr25519 verification.
+	fn seal_state_proof_verify(r: u32, ) -> Weight {
+		Self::seal_sr25519_verify(r)
+	}
+	/// Estimate, pending a run of the `seal_state_proof_verify_per_node` benchmark: every
+	/// additional node is decoded and hashed once.
+	fn seal_state_proof_verify_per_node(n: u32, ) -> Weight {
+		Self::seal_hash_blake2_256(n)
+	}
+	/// Estimate, pending a run of the `seal_state_proof_verify_per_byte` benchmark: every byte of
+	/// the proof, key and value is hashed once.
+	fn seal_state_proof_verify_per_byte(n: u32, ) -> Weight {
+		Self::seal_hash_blake2_256_per_byte(n)
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:0)
@@ -2614,6 +2662,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 6).saturating_mul(r.into()))
 	}
+	/// Estimate, pending a run of the `seal_block_weight_remaining` benchmark: like
+	/// `seal_block_number` it returns a value without touching non-whitelisted storage.
+	fn seal_block_weight_remaining(r: u32, ) -> Weight {
+		Self::seal_block_number(r)
+	}
+	/// Estimate, pending a run of the `seal_event_topics_count_limit` benchmark: like
+	/// `seal_caller_is_root` it returns a constant without any storage access.
+	fn seal_event_topics_count_limit(r: u32, ) -> Weight {
+		Self::seal_caller_is_root(r)
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:2 w:0)
@@ -2946,6 +3004,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 10).saturating_mul(r.into()))
 	}
+	/// Estimate, pending a run of the `seal_vrf_random` benchmark: like `seal_random` it reads
+	/// one randomness value from storage and hashes it with a subject of bounded length.
+	fn seal_vrf_random(r: u32, ) -> Weight {
+		Self::seal_random(r)
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:0)
@@ -3230,6 +3293,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(Weight::from_parts(0, 1).saturating_mul(n.into()))
 	}
+	/// Upper bound, pending a run of the `seal_storage_batch` benchmark. Every operation is charged
+	/// separately, so the call overhead is below the cost of a `seal_get_storage` call.
+	fn seal_storage_batch(r: u32, ) -> Weight {
+		Self::seal_get_storage(r)
+	}
+	/// Upper bound, pending a run of the `seal_storage_batch_per_byte` benchmark. Decoding the
+	/// operations costs less per byte than writing them to storage.
+	fn seal_storage_batch_per_byte(n: u32, ) -> Weight {
+		Self::seal_set_storage_per_new_byte(n)
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:1602 w:1601)
@@ -3685,6 +3758,21 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 			.saturating_add(Weight::from_parts(0, 112).saturating_mul(r.into()))
 	}
+	/// Estimate, pending a run of the `seal_state_proof_verify` benchmark: a verification
+	/// hashes the nodes of a small proof, which costs less than an sr25519 verification.
+	fn seal_state_proof_verify(r: u32, ) -> Weight {
+		Self::seal_sr25519_verify(r)
+	}
+	/// Estimate, pending a run of the `seal_state_proof_verify_per_node` benchmark: every
+	/// additional node is decoded and hashed once.
+	fn seal_state_proof_verify_per_node(n: u32, ) -> Weight {
+		Self::seal_hash_blake2_256(n)
+	}
+	/// Estimate, pending a run of the `seal_state_proof_verify_per_byte` benchmark: every byte of
+	/// the proof, key and value is hashed once.
+	fn seal_state_proof_verify_per_byte(n: u32, ) -> Weight {
+		Self::seal_hash_blake2_256_per_byte(n)
+	}
 	/// Storage: `Contracts::MigrationInProgress` (r:1 w:0)
 	/// Proof: `Contracts::MigrationInProgress` (`max_values`: Some(1), `max_size`: Some(1026), added: 1521, mode: `Measured`)
 	/// Storage: `System::Account` (r:1 w:0)
@@ -715,6 +715,22 @@ benchmarks_instance_pallet! {
 		}
 	}
 
+	bulk_mint {
+		let n in 0 .. T::MaxBulkMintItems::get() as u32;
+		let (collection, caller, _) = create_collection::<T, I>();
+		let items: BoundedVec<_, _> = (0..n)
+			.map(|i| (T::Helper::item(i as u16), None))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}: _(SystemOrigin::Signed(caller.clone()), collection, items)
+	verify {
+		if !n.is_zero() {
+			let item = T::Helper::item((n - 1) as u16);
+			assert_last_event::<T, I>(Event::Issued { collection, item, owner: caller }.into());
+		}
+	}
+
 	create_swap {
 		let (collection, caller, _) = create_collection::<T, I>();
 		let (item1, ..) = mint_item::<T, I>(0);
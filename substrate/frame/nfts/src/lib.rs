@@ -33,6 +33,8 @@ mod benchmarking;
 pub mod migration;
 #[cfg(test)]
 pub mod mock;
+#[cfg(any(feature = "uniques-migration", test))]
+pub mod pallet_uniques_to_nfts_migration;
 #[cfg(test)]
 mod tests;
 
@@ -190,6 +192,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxAttributesPerCall: Get<u32>;
 
+		/// The max number of items that could be minted with a single `bulk_mint` call.
+		#[pallet::constant]
+		type MaxBulkMintItems: Get<u32>;
+
 		/// Disables some of pallet's features.
 		#[pallet::constant]
 		type Features: Get<PalletFeatures>;
@@ -1895,6 +1901,55 @@ pub mod pallet {
 			Self::validate_signature(&Encode::encode(&data), &signature, &signer)?;
 			Self::do_set_attributes_pre_signed(origin, data, signer)
 		}
+
+		/// Mint multiple items of a particular collection at once.
+		///
+		/// The origin must be Signed and the sender must be the Issuer of the `collection`.
+		///
+		/// - `collection`: The collection of the items to be minted.
+		/// - `items`: The identifiers of the new items, each paired with an optional account into
+		///   which the item will be minted. When no account is given, the item is minted into the
+		///   sender's account.
+		///
+		/// Each item is minted with the collection's default item settings. The call fails as a
+		/// whole if any of the items can't be minted.
+		///
+		/// Note: the deposits will be taken from the `origin`.
+		///
+		/// Emits `Issued` event for every minted item.
+		///
+		/// Weight: `O(N)` where `N` is the number of `items`.
+		#[pallet::call_index(39)]
+		#[pallet::weight(T::WeightInfo::bulk_mint(items.len() as u32))]
+		pub fn bulk_mint(
+			origin: OriginFor<T>,
+			collection: T::CollectionId,
+			items: BoundedVec<(T::ItemId, Option<AccountIdLookupOf<T>>), T::MaxBulkMintItems>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			ensure!(
+				Self::has_role(&collection, &origin, CollectionRole::Issuer),
+				Error::<T, I>::NoPermission
+			);
+			let item_config =
+				ItemConfig { settings: Self::get_default_item_settings(&collection)? };
+
+			for (item, maybe_mint_to) in items {
+				let mint_to = match maybe_mint_to {
+					Some(mint_to) => T::Lookup::lookup(mint_to)?,
+					None => origin.clone(),
+				};
+				Self::do_mint(
+					collection,
+					item,
+					Some(origin.clone()),
+					mint_to,
+					item_config,
+					|_, _| Ok(()),
+				)?;
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -0,0 +1,553 @@
+// This file is part of Substrate.
+
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A migration moving the state of `pallet_uniques` into `pallet_nfts`.
+//!
+//! Collections, items, metadata, attributes, prices and ownership acceptances are moved over,
+//! keeping their identifiers. Deposits stay reserved on the same accounts, so both pallets must
+//! use the same `Currency`. Collection and item flags of `pallet_uniques` are translated into the
+//! corresponding `pallet_nfts` settings:
+//!
+//! - a frozen collection has its `TransferableItems` setting disabled;
+//! - a free-holding collection has its `DepositRequired` setting disabled;
+//! - a collection with frozen metadata has its `UnlockedMetadata` setting disabled;
+//! - a collection with a max supply has its `UnlockedMaxSupply` setting disabled;
+//! - a frozen item has its `Transferable` setting disabled;
+//! - an item with frozen metadata has its `UnlockedMetadata` setting disabled.
+//!
+//! Attributes are placed in the `CollectionOwner` namespace. Metadata and attributes that don't
+//! fit into the bounds of `pallet_nfts` are dropped, with their deposits accounted to the
+//! collection owner so they are released when the collection gets destroyed.
+//!
+//! `NextCollectionId` is moved past the largest migrated collection identifier, so collections
+//! created afterwards don't clash with the migrated ones.
+//!
+//! The migration runs within a single block. To keep its weight bounded, it does nothing if
+//! `pallet_uniques` holds more than `MaxEntries` storage entries. It also does nothing if any of
+//! the collections to migrate already exists in `pallet_nfts`.
+//!
+//! The module is only available with the `uniques-migration` feature enabled.
+
+use super::*;
+use enumflags2::BitFlags;
+use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
+use log;
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+mod uniques {
+	use super::*;
+	use frame_support::storage_alias;
+	use pallet_uniques::{Config, Pallet};
+
+	pub type DepositBalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
+
+	#[derive(Encode, Decode)]
+	pub struct CollectionDetails<AccountId, DepositBalance> {
+		pub owner: AccountId,
+		pub issuer: AccountId,
+		pub admin: AccountId,
+		pub freezer: AccountId,
+		pub total_deposit: DepositBalance,
+		pub free_holding: bool,
+		pub items: u32,
+		pub item_metadatas: u32,
+		pub attributes: u32,
+		pub is_frozen: bool,
+	}
+
+	#[derive(Encode, Decode)]
+	pub struct ItemDetails<AccountId, DepositBalance> {
+		pub owner: AccountId,
+		pub approved: Option<AccountId>,
+		pub is_frozen: bool,
+		pub deposit: DepositBalance,
+	}
+
+	/// The layout shared by collection and item metadata.
+	#[derive(Encode, Decode)]
+	pub struct Metadata<DepositBalance> {
+		pub deposit: DepositBalance,
+		pub data: Vec<u8>,
+		pub is_frozen: bool,
+	}
+
+	#[storage_alias]
+	pub type Class<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::CollectionId,
+		CollectionDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T>>,
+	>;
+
+	#[storage_alias]
+	pub type OwnershipAcceptance<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as SystemConfig>::AccountId,
+		<T as Config>::CollectionId,
+	>;
+
+	#[storage_alias]
+	pub type Account<T: Config> = StorageNMap<
+		Pallet<T>,
+		(
+			NMapKey<Blake2_128Concat, <T as SystemConfig>::AccountId>,
+			NMapKey<Blake2_128Concat, <T as Config>::CollectionId>,
+			NMapKey<Blake2_128Concat, <T as Config>::ItemId>,
+		),
+		(),
+	>;
+
+	#[storage_alias]
+	pub type ClassAccount<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as SystemConfig>::AccountId,
+		Blake2_128Concat,
+		<T as Config>::CollectionId,
+		(),
+	>;
+
+	#[storage_alias]
+	pub type Asset<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::CollectionId,
+		Blake2_128Concat,
+		<T as Config>::ItemId,
+		ItemDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T>>,
+	>;
+
+	#[storage_alias]
+	pub type ClassMetadataOf<T: Config> = StorageMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::CollectionId,
+		Metadata<DepositBalanceOf<T>>,
+	>;
+
+	#[storage_alias]
+	pub type InstanceMetadataOf<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::CollectionId,
+		Blake2_128Concat,
+		<T as Config>::ItemId,
+		Metadata<DepositBalanceOf<T>>,
+	>;
+
+	#[storage_alias]
+	pub type Attribute<T: Config> = StorageNMap<
+		Pallet<T>,
+		(
+			NMapKey<Blake2_128Concat, <T as Config>::CollectionId>,
+			NMapKey<Blake2_128Concat, Option<<T as Config>::ItemId>>,
+			NMapKey<Blake2_128Concat, Vec<u8>>,
+		),
+		(Vec<u8>, DepositBalanceOf<T>),
+	>;
+
+	#[storage_alias]
+	pub type ItemPriceOf<T: Config> = StorageDoubleMap<
+		Pallet<T>,
+		Blake2_128Concat,
+		<T as Config>::CollectionId,
+		Blake2_128Concat,
+		<T as Config>::ItemId,
+		(DepositBalanceOf<T>, Option<<T as SystemConfig>::AccountId>),
+	>;
+
+	#[storage_alias]
+	pub type CollectionMaxSupply<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::CollectionId, u32>;
+}
+
+/// Moves all the collections and items of `pallet_uniques` into `pallet_nfts`.
+///
+/// `MaxEntries` is the maximum number of storage entries of `pallet_uniques` the migration is
+/// allowed to move. See the [module level documentation](self) for how the state gets translated.
+pub struct MigrateUniquesToNfts<T, MaxEntries>(sp_std::marker::PhantomData<(T, MaxEntries)>);
+
+impl<T, MaxEntries> MigrateUniquesToNfts<T, MaxEntries>
+where
+	T: Config
+		+ pallet_uniques::Config<
+			CollectionId = <T as Config>::CollectionId,
+			ItemId = <T as Config>::ItemId,
+			Currency = <T as Config>::Currency,
+		>,
+	<T as Config>::CollectionId: Ord,
+	MaxEntries: Get<u32>,
+{
+	/// Returns the number of storage entries of `pallet_uniques` to migrate, counting at most
+	/// `MaxEntries + 1` of them.
+	fn count_entries() -> u32 {
+		fn keys<K>(iter: impl Iterator<Item = K>) -> impl Iterator<Item = ()> {
+			iter.map(|_| ())
+		}
+
+		keys(uniques::Class::<T>::iter_keys())
+			.chain(keys(uniques::ClassMetadataOf::<T>::iter_keys()))
+			.chain(keys(uniques::CollectionMaxSupply::<T>::iter_keys()))
+			.chain(keys(uniques::ClassAccount::<T>::iter_keys()))
+			.chain(keys(uniques::Asset::<T>::iter_keys()))
+			.chain(keys(uniques::InstanceMetadataOf::<T>::iter_keys()))
+			.chain(keys(uniques::Account::<T>::iter_keys()))
+			.chain(keys(uniques::Attribute::<T>::iter_keys()))
+			.chain(keys(uniques::ItemPriceOf::<T>::iter_keys()))
+			.chain(keys(uniques::OwnershipAcceptance::<T>::iter_keys()))
+			.take(MaxEntries::get().saturating_add(1) as usize)
+			.count() as u32
+	}
+
+	/// Returns the first collection of `pallet_uniques` whose identifier is already taken in
+	/// `pallet_nfts`, if any.
+	fn find_clashing_collection() -> Option<<T as Config>::CollectionId> {
+		uniques::Class::<T>::iter_keys()
+			.find(|collection| Collection::<T>::contains_key(collection))
+	}
+
+	fn migrate_collections(reads: &mut u64, writes: &mut u64) {
+		let mut max_collection = None;
+		for (collection, old) in uniques::Class::<T>::drain() {
+			max_collection = max_collection.max(Some(collection));
+			let maybe_metadata = uniques::ClassMetadataOf::<T>::take(&collection);
+			let max_supply = uniques::CollectionMaxSupply::<T>::take(&collection);
+			*reads += 3;
+			*writes += 3;
+
+			let mut disabled = BitFlags::<CollectionSetting>::EMPTY;
+			if old.is_frozen {
+				disabled.insert(CollectionSetting::TransferableItems);
+			}
+			if old.free_holding {
+				disabled.insert(CollectionSetting::DepositRequired);
+			}
+			if maybe_metadata.as_ref().map_or(false, |metadata| metadata.is_frozen) {
+				disabled.insert(CollectionSetting::UnlockedMetadata);
+			}
+			if max_supply.is_some() {
+				disabled.insert(CollectionSetting::UnlockedMaxSupply);
+			}
+			CollectionConfigOf::<T>::insert(
+				&collection,
+				CollectionConfig {
+					settings: CollectionSettings::from_disabled(disabled),
+					max_supply,
+					mint_settings: MintSettings::default(),
+				},
+			);
+
+			let roles = Pallet::<T>::group_roles_by_account(vec![
+				(old.issuer, CollectionRole::Issuer),
+				(old.admin, CollectionRole::Admin),
+				(old.freezer, CollectionRole::Freezer),
+			]);
+			for (account, roles) in roles {
+				CollectionRoleOf::<T>::insert(&collection, account, roles);
+				*writes += 1;
+			}
+
+			if let Some(metadata) = maybe_metadata {
+				match BoundedVec::try_from(metadata.data) {
+					Ok(data) => {
+						CollectionMetadataOf::<T>::insert(
+							&collection,
+							CollectionMetadata { deposit: metadata.deposit, data },
+						);
+						*writes += 1;
+					},
+					Err(_) => log::warn!(
+						target: LOG_TARGET,
+						"Dropping metadata of collection {:?} exceeding the string limit",
+						collection,
+					),
+				}
+			}
+
+			CollectionAccount::<T>::insert(&old.owner, &collection, ());
+			Collection::<T>::insert(
+				&collection,
+				CollectionDetails {
+					owner: old.owner,
+					// Item deposits are subtracted once the items get migrated.
+					owner_deposit: old.total_deposit,
+					items: old.items,
+					item_metadatas: old.item_metadatas,
+					item_configs: 0,
+					attributes: old.attributes,
+				},
+			);
+			*writes += 3;
+		}
+
+		if let Some(next) = max_collection.and_then(|collection| collection.increment()) {
+			if NextCollectionId::<T>::get().map_or(true, |current| current < next) {
+				NextCollectionId::<T>::put(next);
+				*writes += 1;
+			}
+			*reads += 1;
+		}
+	}
+
+	fn migrate_items(reads: &mut u64, writes: &mut u64) {
+		for (collection, item, old) in uniques::Asset::<T>::drain() {
+			*reads += 2;
+			*writes += 1;
+
+			let Some(collection_owner) = Collection::<T>::mutate(&collection, |maybe_details| {
+				maybe_details.as_mut().map(|details| {
+					details.owner_deposit.saturating_reduce(old.deposit);
+					details.item_configs.saturating_inc();
+					details.owner.clone()
+				})
+			}) else {
+				log::warn!(
+					target: LOG_TARGET,
+					"Dropping item {:?} of unknown collection {:?}",
+					item,
+					collection,
+				);
+				continue
+			};
+
+			let mut approvals = ApprovalsOf::<T>::default();
+			if let Some(approved) = old.approved {
+				// A single approval always fits.
+				let _ = approvals.try_insert(approved, None);
+			}
+
+			let mut disabled = BitFlags::<ItemSetting>::EMPTY;
+			if old.is_frozen {
+				disabled.insert(ItemSetting::Transferable);
+			}
+			ItemConfigOf::<T>::insert(
+				&collection,
+				&item,
+				ItemConfig { settings: ItemSettings::from_disabled(disabled) },
+			);
+
+			Account::<T>::insert((&old.owner, &collection, &item), ());
+			Item::<T>::insert(
+				&collection,
+				&item,
+				ItemDetails {
+					owner: old.owner,
+					approvals,
+					deposit: ItemDeposit { account: collection_owner, amount: old.deposit },
+				},
+			);
+			*writes += 4;
+		}
+
+		for (collection, item, metadata) in uniques::InstanceMetadataOf::<T>::drain() {
+			*reads += 1;
+			*writes += 1;
+
+			let Ok(data) = BoundedVec::try_from(metadata.data) else {
+				log::warn!(
+					target: LOG_TARGET,
+					"Dropping metadata of item {:?} of collection {:?} exceeding the string limit",
+					item,
+					collection,
+				);
+				Collection::<T>::mutate(&collection, |maybe_details| {
+					if let Some(details) = maybe_details {
+						details.item_metadatas.saturating_dec();
+					}
+				});
+				*reads += 1;
+				*writes += 1;
+				continue
+			};
+
+			if metadata.is_frozen {
+				let mut new_config = false;
+				ItemConfigOf::<T>::mutate(&collection, &item, |maybe_config| {
+					let config = maybe_config.get_or_insert_with(|| {
+						new_config = true;
+						ItemConfig { settings: ItemSettings::all_enabled() }
+					});
+					let mut disabled = config.settings.get_disabled();
+					disabled.insert(ItemSetting::UnlockedMetadata);
+					config.settings = ItemSettings::from_disabled(disabled);
+				});
+				if new_config {
+					Collection::<T>::mutate(&collection, |maybe_details| {
+						if let Some(details) = maybe_details {
+							details.item_configs.saturating_inc();
+						}
+					});
+					*reads += 1;
+					*writes += 1;
+				}
+				*reads += 1;
+				*writes += 1;
+			}
+
+			ItemMetadataOf::<T>::insert(
+				&collection,
+				&item,
+				ItemMetadata {
+					deposit: ItemMetadataDeposit { account: None, amount: metadata.deposit },
+					data,
+				},
+			);
+			*writes += 1;
+		}
+	}
+
+	fn migrate_attributes(reads: &mut u64, writes: &mut u64) {
+		for ((collection, maybe_item, key), (value, deposit)) in uniques::Attribute::<T>::drain() {
+			*reads += 1;
+			*writes += 1;
+
+			match (BoundedVec::try_from(key), BoundedVec::try_from(value)) {
+				(Ok(key), Ok(value)) => {
+					Attribute::<T>::insert(
+						(&collection, maybe_item, AttributeNamespace::CollectionOwner, &key),
+						(value, AttributeDeposit { account: None, amount: deposit }),
+					);
+					*writes += 1;
+				},
+				_ => {
+					log::warn!(
+						target: LOG_TARGET,
+						"Dropping attribute of collection {:?} exceeding the key or value limit",
+						collection,
+					);
+					Collection::<T>::mutate(&collection, |maybe_details| {
+						if let Some(details) = maybe_details {
+							details.attributes.saturating_dec();
+						}
+					});
+					*reads += 1;
+					*writes += 1;
+				},
+			}
+		}
+	}
+
+	fn migrate_accounts(reads: &mut u64, writes: &mut u64) {
+		for (collection, item, price) in uniques::ItemPriceOf::<T>::drain() {
+			ItemPriceOf::<T>::insert(&collection, &item, price);
+			*reads += 1;
+			*writes += 2;
+		}
+
+		for (who, collection) in uniques::OwnershipAcceptance::<T>::drain() {
+			OwnershipAcceptance::<T>::insert(&who, &collection);
+			*reads += 1;
+			*writes += 2;
+		}
+
+		// The account indexes got rebuilt while migrating collections and items.
+		let removed = uniques::Account::<T>::clear(u32::MAX, None).unique as u64;
+		*writes += removed;
+		let removed = uniques::ClassAccount::<T>::clear(u32::MAX, None).unique as u64;
+		*writes += removed;
+	}
+}
+
+impl<T, MaxEntries> OnRuntimeUpgrade for MigrateUniquesToNfts<T, MaxEntries>
+where
+	T: Config
+		+ pallet_uniques::Config<
+			CollectionId = <T as Config>::CollectionId,
+			ItemId = <T as Config>::ItemId,
+			Currency = <T as Config>::Currency,
+		>,
+	<T as Config>::CollectionId: Ord,
+	MaxEntries: Get<u32>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let entries = Self::count_entries();
+		if entries > MaxEntries::get() {
+			log::error!(
+				target: LOG_TARGET,
+				"pallet_uniques holds more than {} entries, skipping the migration",
+				MaxEntries::get(),
+			);
+			return T::DbWeight::get().reads(entries.into())
+		}
+
+		let mut reads = entries as u64;
+		let mut writes = 0u64;
+
+		if let Some(collection) = Self::find_clashing_collection() {
+			log::error!(
+				target: LOG_TARGET,
+				"Collection {:?} exists in both pallets, skipping the migration",
+				collection,
+			);
+			reads += uniques::Class::<T>::iter_keys().count() as u64 * 2;
+			return T::DbWeight::get().reads(reads)
+		}
+
+		Self::migrate_collections(&mut reads, &mut writes);
+		Self::migrate_items(&mut reads, &mut writes);
+		Self::migrate_attributes(&mut reads, &mut writes);
+		Self::migrate_accounts(&mut reads, &mut writes);
+
+		log::info!(
+			target: LOG_TARGET,
+			"Migrated pallet_uniques to pallet_nfts with {} reads and {} writes",
+			reads,
+			writes,
+		);
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		ensure!(
+			Self::count_entries() <= MaxEntries::get(),
+			"pallet_uniques holds more entries than the migration may move"
+		);
+		ensure!(
+			Self::find_clashing_collection().is_none(),
+			"collections of pallet_uniques clash with existing collections of pallet_nfts"
+		);
+		let collections =
+			(Collection::<T>::iter().count() + uniques::Class::<T>::iter_keys().count()) as u32;
+		let items = (Item::<T>::iter().count() + uniques::Asset::<T>::iter_keys().count()) as u32;
+		Ok((collections, items).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let (collections, items): (u32, u32) = Decode::decode(&mut state.as_slice())
+			.expect("the state parameter should be something that was generated by pre_upgrade");
+		ensure!(
+			Collection::<T>::iter().count() as u32 == collections,
+			"all collections of pallet_uniques should have been moved to pallet_nfts"
+		);
+		ensure!(
+			Item::<T>::iter().count() as u32 == items,
+			"all items of pallet_uniques should have been moved to pallet_nfts"
+		);
+		ensure!(
+			uniques::Class::<T>::iter_keys().next().is_none() &&
+				uniques::Asset::<T>::iter_keys().next().is_none(),
+			"pallet_uniques should not hold any collection or item after the migration"
+		);
+		Ok(())
+	}
+}
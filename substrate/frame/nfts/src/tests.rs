@@ -455,6 +455,49 @@ fn mint_should_work() {
 	});
 }
 
+#[test]
+fn bulk_mint_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			collection_config_with_all_settings_enabled()
+		));
+
+		// only the issuer can bulk mint
+		assert_noop!(
+			Nfts::bulk_mint(
+				RuntimeOrigin::signed(account(2)),
+				0,
+				bvec![(42, None), (43, Some(account(2)))]
+			),
+			Error::<Test>::NoPermission
+		);
+
+		assert_ok!(Nfts::bulk_mint(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			bvec![(42, None), (43, Some(account(2)))]
+		));
+		assert_eq!(items(), vec![(account(1), 0, 42), (account(2), 0, 43)]);
+		assert_eq!(Collection::<Test>::get(0).unwrap().items, 2);
+		assert_eq!(Balances::reserved_balance(&account(1)), 2);
+		assert_eq!(ItemConfigOf::<Test>::get(0, 43), Some(default_item_config()));
+		assert!(events().contains(&Event::<Test>::Issued {
+			collection: 0,
+			item: 43,
+			owner: account(2)
+		}));
+
+		// the whole batch fails if any of the items can't be minted
+		assert_noop!(
+			Nfts::bulk_mint(RuntimeOrigin::signed(account(1)), 0, bvec![(44, None), (42, None)]),
+			Error::<Test>::AlreadyExists
+		);
+	});
+}
+
 #[test]
 fn transfer_should_work() {
 	new_test_ext().execute_with(|| {
@@ -3718,3 +3761,126 @@ fn basic_create_collection_with_id_should_work() {
 		);
 	});
 }
+
+#[test]
+fn migrate_from_uniques_should_work() {
+	use crate::pallet_uniques_to_nfts_migration::MigrateUniquesToNfts;
+	use frame_support::traits::{ConstU32, OnRuntimeUpgrade};
+	type Migration = MigrateUniquesToNfts<Test, ConstU32<100>>;
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(account(1)), 0, account(2)));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(account(2)), 0, 43, account(3)));
+		assert_ok!(Uniques::freeze(RuntimeOrigin::signed(account(2)), 0, 43));
+		assert_ok!(Uniques::approve_transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(4)));
+		assert_ok!(Uniques::set_attribute(
+			RuntimeOrigin::signed(account(1)),
+			0,
+			None,
+			bvec![0],
+			bvec![1]
+		));
+		assert_ok!(Uniques::set_metadata(RuntimeOrigin::signed(account(1)), 0, 42, bvec![0], true));
+		let reserved = Balances::reserved_balance(&account(1));
+
+		#[cfg(feature = "try-runtime")]
+		let state = Migration::pre_upgrade().unwrap();
+		Migration::on_runtime_upgrade();
+		#[cfg(feature = "try-runtime")]
+		assert_ok!(Migration::post_upgrade(state));
+
+		assert_eq!(Uniques::collection_owner(0), None);
+		assert_eq!(Uniques::owner(0, 42), None);
+		assert_eq!(collections(), vec![(account(1), 0)]);
+		assert_eq!(items(), vec![(account(3), 0, 42), (account(3), 0, 43)]);
+
+		// Deposits stay reserved, item deposits get tracked per item.
+		assert_eq!(Balances::reserved_balance(&account(1)), reserved);
+		assert_eq!(Collection::<Test>::get(0).unwrap().owner_deposit, reserved - 2);
+		assert_eq!(
+			CollectionRoleOf::<Test>::get(0, account(2)),
+			Some(CollectionRoles(
+				CollectionRole::Issuer | CollectionRole::Admin | CollectionRole::Freezer
+			))
+		);
+
+		let item = Item::<Test>::get(0, 42).unwrap();
+		assert!(item.approvals.contains_key(&account(4)));
+		assert_eq!(item.deposit, ItemDeposit { account: account(1), amount: 1 });
+		assert!(!ItemConfigOf::<Test>::get(0, 42)
+			.unwrap()
+			.is_setting_enabled(ItemSetting::UnlockedMetadata));
+		assert!(!ItemConfigOf::<Test>::get(0, 43)
+			.unwrap()
+			.is_setting_enabled(ItemSetting::Transferable));
+		assert_eq!(ItemMetadataOf::<Test>::get(0, 42).unwrap().data, bvec![0]);
+		assert_eq!(
+			Attribute::<Test>::get((0, None::<u32>, AttributeNamespace::CollectionOwner, bvec![0]))
+				.map(|(value, _)| value),
+			Some(bvec![1])
+		);
+
+		// The migrated collection keeps working.
+		assert_ok!(Nfts::transfer(RuntimeOrigin::signed(account(3)), 0, 42, account(4)));
+		assert_eq!(items(), vec![(account(3), 0, 43), (account(4), 0, 42)]);
+
+		// New collections don't clash with the migrated one.
+		assert_eq!(NextCollectionId::<Test>::get(), Some(1));
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_eq!(collections(), vec![(account(1), 0), (account(1), 1)]);
+	});
+}
+
+#[test]
+fn migrate_from_uniques_skips_clashing_collections() {
+	use crate::pallet_uniques_to_nfts_migration::MigrateUniquesToNfts;
+	use frame_support::traits::{ConstU32, OnRuntimeUpgrade};
+	type Migration = MigrateUniquesToNfts<Test, ConstU32<100>>;
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		assert_ok!(Nfts::force_create(
+			RuntimeOrigin::root(),
+			account(1),
+			default_collection_config()
+		));
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(account(1)), 0, account(2)));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+
+		#[cfg(feature = "try-runtime")]
+		assert!(Migration::pre_upgrade().is_err());
+		Migration::on_runtime_upgrade();
+
+		// Nothing got moved.
+		assert_eq!(Uniques::owner(0, 42), Some(account(3)));
+		assert_eq!(collections(), vec![(account(1), 0)]);
+		assert_eq!(items(), vec![]);
+	});
+}
+
+#[test]
+fn migrate_from_uniques_skips_too_many_entries() {
+	use crate::pallet_uniques_to_nfts_migration::MigrateUniquesToNfts;
+	use frame_support::traits::{ConstU32, OnRuntimeUpgrade};
+
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&account(1), 100);
+		// Stores the collection and the item, each with an account index entry.
+		assert_ok!(Uniques::create(RuntimeOrigin::signed(account(1)), 0, account(2)));
+		assert_ok!(Uniques::mint(RuntimeOrigin::signed(account(2)), 0, 42, account(3)));
+
+		MigrateUniquesToNfts::<Test, ConstU32<3>>::on_runtime_upgrade();
+		assert_eq!(Uniques::owner(0, 42), Some(account(3)));
+		assert_eq!(items(), vec![]);
+
+		MigrateUniquesToNfts::<Test, ConstU32<4>>::on_runtime_upgrade();
+		assert_eq!(Uniques::owner(0, 42), None);
+		assert_eq!(items(), vec![(account(3), 0, 42)]);
+	});
+}
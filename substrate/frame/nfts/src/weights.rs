@@ -90,6 +90,7 @@ pub trait WeightInfo {
 	fn claim_swap() -> Weight;
 	fn mint_pre_signed(n: u32, ) -> Weight;
 	fn set_attributes_pre_signed(n: u32, ) -> Weight;
+	fn bulk_mint(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_nfts using the Substrate node and recommended hardware.
@@ -774,6 +775,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
 	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, MaxBulkMintItems]`.
+	///
+	/// Every item is charged a whole `mint`, including all of its storage accesses, on top of the
+	/// deposit reserved from the sender. This is an upper bound until the `bulk_mint` benchmark is
+	/// run for this runtime.
+	fn bulk_mint(n: u32, ) -> Weight {
+		Self::mint().saturating_mul(n.into())
+			.saturating_add(Weight::from_parts(0, 2603))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -1457,4 +1471,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2921).saturating_mul(n.into()))
 	}
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `n` is `[0, MaxBulkMintItems]`.
+	///
+	/// Every item is charged a whole `mint`, including all of its storage accesses, on top of the
+	/// deposit reserved from the sender. This is an upper bound until the `bulk_mint` benchmark is
+	/// run for this runtime.
+	fn bulk_mint(n: u32, ) -> Weight {
+		Self::mint().saturating_mul(n.into())
+			.saturating_add(Weight::from_parts(0, 2603))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -34,7 +34,14 @@ use sp_weights::Weight;
 /// The return type of a `Dispatchable` in frame. When returned explicitly from
 /// a dispatchable function it allows overriding the default `PostDispatchInfo`
 /// returned from a dispatch.
-pub type DispatchResultWithPostInfo = sp_runtime::DispatchResultWithInfo<PostDispatchInfo>;
+pub type DispatchResultWithPostInfo = DispatchResultWithInfo<PostDispatchInfo>;
+
+/// Generic version of `DispatchResultWithPostInfo` where `T` is the post dispatch information.
+///
+/// Dispatchables that need to return custom metadata alongside the `PostDispatchInfo` can use
+/// e.g. `DispatchResultWithInfo<(PostDispatchInfo, u64)>`. The `PostDispatchInfo` part can be
+/// recovered using [`extract_post_dispatch_info`].
+pub type DispatchResultWithInfo<T> = sp_runtime::DispatchResultWithInfo<T>;
 
 /// Unaugmented version of `DispatchResultWithPostInfo` that can be returned from
 /// dispatchable functions and is automatically converted to the augmented type. Should be
@@ -235,22 +242,52 @@ impl GetDispatchInfo for () {
 	}
 }
 
-/// Extract the actual weight from a dispatch result if any or fall back to the default weight.
-pub fn extract_actual_weight(result: &DispatchResultWithPostInfo, info: &DispatchInfo) -> Weight {
+/// A type that carries a [`PostDispatchInfo`] and possibly some additional post dispatch metadata.
+pub trait GetPostDispatchInfo {
+	/// Return the `PostDispatchInfo` contained in this type.
+	fn post_dispatch_info(&self) -> &PostDispatchInfo;
+}
+
+impl GetPostDispatchInfo for PostDispatchInfo {
+	fn post_dispatch_info(&self) -> &PostDispatchInfo {
+		self
+	}
+}
+
+impl<T> GetPostDispatchInfo for (PostDispatchInfo, T) {
+	fn post_dispatch_info(&self) -> &PostDispatchInfo {
+		&self.0
+	}
+}
+
+/// Extract the `PostDispatchInfo` from a generic dispatch result, regardless of whether the
+/// dispatch succeeded or failed.
+pub fn extract_post_dispatch_info<T>(result: &DispatchResultWithInfo<T>) -> &PostDispatchInfo
+where
+	T: GetPostDispatchInfo
+		+ Eq
+		+ PartialEq
+		+ Clone
+		+ Copy
+		+ Encode
+		+ Decode
+		+ sp_runtime::traits::Printable,
+{
 	match result {
 		Ok(post_info) => post_info,
 		Err(err) => &err.post_info,
 	}
-	.calc_actual_weight(info)
+	.post_dispatch_info()
+}
+
+/// Extract the actual weight from a dispatch result if any or fall back to the default weight.
+pub fn extract_actual_weight(result: &DispatchResultWithPostInfo, info: &DispatchInfo) -> Weight {
+	extract_post_dispatch_info(result).calc_actual_weight(info)
 }
 
 /// Extract the actual pays_fee from a dispatch result if any or fall back to the default weight.
 pub fn extract_actual_pays_fee(result: &DispatchResultWithPostInfo, info: &DispatchInfo) -> Pays {
-	match result {
-		Ok(post_info) => post_info,
-		Err(err) => &err.post_info,
-	}
-	.pays_fee(info)
+	extract_post_dispatch_info(result).pays_fee(info)
 }
 
 /// Weight information that is only available post dispatch.
@@ -905,6 +942,20 @@ mod weight_tests {
 			Pays::No
 		);
 	}
+
+	#[test]
+	fn extract_post_dispatch_info_works_with_custom_info() {
+		let info = from_post_weight_info(Some(7), Pays::No);
+		let ok: DispatchResultWithInfo<(PostDispatchInfo, u64)> = Ok((info, 42));
+		assert_eq!(extract_post_dispatch_info(&ok), &info);
+
+		let err: DispatchResultWithInfo<(PostDispatchInfo, u64)> =
+			Err(sp_runtime::DispatchErrorWithPostInfo {
+				post_info: (info, 42),
+				error: DispatchError::BadOrigin,
+			});
+		assert_eq!(extract_post_dispatch_info(&err), &info);
+	}
 }
 
 #[cfg(test)]
@@ -814,11 +814,7 @@ fn test_metadata() {
 	let extrinsic = ExtrinsicMetadata {
 		ty: meta_type::<UncheckedExtrinsic>(),
 		version: 4,
-		signed_extensions: vec![SignedExtensionMetadata {
-			identifier: "UnitSignedExtension",
-			ty: meta_type::<()>(),
-			additional_signed: meta_type::<()>(),
-		}],
+		signed_extensions: vec![],
 	};
 
 	let expected_metadata: RuntimeMetadataPrefixed =
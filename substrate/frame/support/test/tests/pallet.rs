@@ -1809,11 +1809,7 @@ fn metadata() {
 
 	let extrinsic = ExtrinsicMetadata {
 		version: 4,
-		signed_extensions: vec![SignedExtensionMetadata {
-			identifier: "UnitSignedExtension",
-			ty: meta_type::<()>(),
-			additional_signed: meta_type::<()>(),
-		}],
+		signed_extensions: vec![],
 		address_ty: meta_type::<<<UncheckedExtrinsic as ExtrinsicT>::SignaturePayload as SignaturePayloadT>::SignatureAddress>(),
 		call_ty: meta_type::<<UncheckedExtrinsic as ExtrinsicT>::Call>(),
 		signature_ty: meta_type::<
@@ -936,11 +936,7 @@ fn metadata() {
 	let extrinsic = ExtrinsicMetadata {
 		ty: scale_info::meta_type::<UncheckedExtrinsic>(),
 		version: 4,
-		signed_extensions: vec![SignedExtensionMetadata {
-			identifier: "UnitSignedExtension",
-			ty: scale_info::meta_type::<()>(),
-			additional_signed: scale_info::meta_type::<()>(),
-		}],
+		signed_extensions: vec![],
 	};
 
 	let expected_metadata: RuntimeMetadataPrefixed =
@@ -53,3 +53,30 @@ fn non_system_digest_item_encoding() {
 	let decoded: DigestItem = Decode::decode(&mut &encoded[..]).unwrap();
 	assert_eq!(item, decoded);
 }
+
+#[test]
+fn block_decode_header_only_works() {
+	use crate::{
+		generic::{Block, Header},
+		testing::ExtrinsicWrapper,
+		traits::{BlakeTwo256, Block as BlockT, Header as HeaderT},
+	};
+
+	type TestBlock = Block<Header<u64, BlakeTwo256>, ExtrinsicWrapper<Vec<u8>>>;
+
+	let header = Header::new(
+		1,
+		Default::default(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+	);
+	let extrinsics = vec![ExtrinsicWrapper::from(vec![1u8; 1024]); 16];
+	let encoded = TestBlock::encode_from(&header, &extrinsics);
+
+	let mut input = &encoded[..];
+	assert_eq!(TestBlock::decode_header_only(&mut input).unwrap(), header);
+	assert_eq!(input, &extrinsics.encode()[..]);
+
+	assert!(TestBlock::decode_header_only(&mut &encoded[..4]).is_err());
+}
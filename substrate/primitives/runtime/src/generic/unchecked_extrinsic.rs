@@ -440,6 +440,16 @@ mod tests {
 		assert_eq!(Ex::decode(&mut &encoded[..]), Ok(ux));
 	}
 
+	#[test]
+	fn nested_tuples_extend_the_pipeline() {
+		// Pipelines longer than the largest supported tuple are built by nesting.
+		type Long = (
+			(TestExtra, TestExtra, TestExtra, TestExtra, TestExtra, TestExtra, TestExtra),
+			(TestExtra, TestExtra, TestExtra, TestExtra, TestExtra, TestExtra, TestExtra),
+		);
+		assert_eq!(Long::metadata().len(), 14);
+	}
+
 	#[test]
 	fn invalid_length_prefix_is_detected() {
 		let ux = Ex::new_unsigned(vec![0u8; 0]);
@@ -534,6 +544,115 @@ mod tests {
 		assert_eq!(opaque_encoded, encoded);
 	}
 
+	#[test]
+	fn pipeline_length_counts_the_extensions() {
+		type Extra = (TestExtra, (TestExtra, ()), TestExtra);
+
+		assert_eq!(TestExtra::PIPELINE_LENGTH, 1);
+		assert_eq!(<()>::PIPELINE_LENGTH, 0);
+		assert_eq!(Extra::PIPELINE_LENGTH, 3);
+		assert_eq!(Extra::metadata().len(), Extra::PIPELINE_LENGTH);
+	}
+
+	#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+	struct RejectAll;
+	impl SignedExtension for RejectAll {
+		const IDENTIFIER: &'static str = "RejectAll";
+		type AccountId = u64;
+		type Call = ();
+		type AdditionalSigned = u32;
+		type Pre = ();
+
+		fn additional_signed(&self) -> sp_std::result::Result<u32, TransactionValidityError> {
+			Ok(42)
+		}
+
+		fn validate(
+			&self,
+			_who: &Self::AccountId,
+			_call: &Self::Call,
+			_info: &DispatchInfoOf<Self::Call>,
+			_len: usize,
+		) -> crate::transaction_validity::TransactionValidity {
+			Err(InvalidTransaction::Call.into())
+		}
+
+		fn pre_dispatch(
+			self,
+			_who: &Self::AccountId,
+			_call: &Self::Call,
+			_info: &DispatchInfoOf<Self::Call>,
+			_len: usize,
+		) -> Result<Self::Pre, TransactionValidityError> {
+			Err(InvalidTransaction::Call.into())
+		}
+
+		fn post_dispatch(
+			_pre: Option<Self::Pre>,
+			_info: &DispatchInfoOf<Self::Call>,
+			_post_info: &crate::traits::PostDispatchInfoOf<Self::Call>,
+			_len: usize,
+			_result: &crate::DispatchResult,
+		) -> Result<(), TransactionValidityError> {
+			Err(InvalidTransaction::Call.into())
+		}
+	}
+
+	#[test]
+	fn optional_extension_is_skipped_when_absent() {
+		let rejected = Err(TransactionValidityError::from(InvalidTransaction::Call));
+
+		assert_eq!(None::<RejectAll>.additional_signed(), Ok(None));
+		assert_eq!(None::<RejectAll>.validate(&TEST_ACCOUNT, &(), &(), 0), Ok(Default::default()));
+		assert_eq!(None::<RejectAll>.pre_dispatch(&TEST_ACCOUNT, &(), &(), 0), Ok(None));
+		assert_eq!(Option::<RejectAll>::post_dispatch(Some(None), &(), &(), 0, &Ok(())), Ok(()));
+
+		assert_eq!(Some(RejectAll).additional_signed(), Ok(Some(42)));
+		assert_eq!(Some(RejectAll).validate(&TEST_ACCOUNT, &(), &(), 0), rejected.clone());
+		assert_eq!(Some(RejectAll).pre_dispatch(&TEST_ACCOUNT, &(), &(), 0), rejected.clone());
+		assert_eq!(
+			Option::<RejectAll>::post_dispatch(Some(Some(())), &(), &(), 0, &Ok(())),
+			rejected.clone()
+		);
+		// Unsigned transactions don't carry the extension, so it is always consulted.
+		assert_eq!(Option::<RejectAll>::post_dispatch(None, &(), &(), 0, &Ok(())), rejected);
+	}
+
+	#[test]
+	fn optional_extension_metadata_and_encoding() {
+		type Extra = (TestExtra, Option<RejectAll>);
+
+		assert_eq!(Option::<RejectAll>::PIPELINE_LENGTH, 1);
+		assert_eq!(Extra::PIPELINE_LENGTH, 2);
+		let metadata = Extra::metadata();
+		assert_eq!(metadata.len(), 2);
+		assert_eq!(metadata[1].identifier, "OptionalRejectAll");
+		assert_eq!(metadata[1].ty, meta_type::<Option<RejectAll>>());
+
+		// Presence is encoded, so `Option<T>` can't replace `T` without breaking the codec.
+		assert_eq!(RejectAll.encode(), Vec::<u8>::new());
+		assert_eq!(Some(RejectAll).encode(), vec![1]);
+		assert_eq!(None::<RejectAll>.encode(), vec![0]);
+		assert_eq!(Some(RejectAll).additional_signed().unwrap().encode(), vec![1, 42, 0, 0, 0]);
+	}
+
+	#[test]
+	fn signer_decides_whether_an_optional_extension_runs() {
+		type OptEx = UncheckedExtrinsic<TestAccountId, (), TestSig, Option<RejectAll>>;
+		let validate = |extra: Option<RejectAll>| {
+			let payload = SignedPayload::new((), extra.clone()).unwrap().encode();
+			let ux = OptEx::new_signed((), TEST_ACCOUNT, TestSig(TEST_ACCOUNT, payload), extra);
+			let xt = <OptEx as Checkable<TestContext>>::check(ux, &Default::default()).unwrap();
+			let (who, extra) = xt.signed.unwrap();
+			extra.validate(&who, &(), &(), 0)
+		};
+
+		// Presence is decoded from the transaction, so a signer can leave the extension out and
+		// skip both its additional signed data and its validity checks.
+		assert_eq!(validate(None), Ok(Default::default()));
+		assert_eq!(validate(Some(RejectAll)), Err(InvalidTransaction::Call.into()));
+	}
+
 	#[test]
 	fn large_bad_prefix_should_work() {
 		let encoded = Compact::<u32>::from(u32::MAX).encode();
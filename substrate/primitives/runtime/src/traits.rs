@@ -1277,6 +1277,14 @@ pub trait Block:
 	/// Creates an encoded block from the given `header` and `extrinsics` without requiring the
 	/// creation of an instance.
 	fn encode_from(header: &Self::Header, extrinsics: &[Self::Extrinsic]) -> Vec<u8>;
+	/// Decodes only the header of an encoded block.
+	///
+	/// The header is the first field of an encoded block, followed by the length prefixed list
+	/// of extrinsics. Only the header is consumed from `input`, the extrinsics are neither
+	/// allocated nor decoded and are left in `input`.
+	fn decode_header_only(input: &mut &[u8]) -> Result<Self::Header, codec::Error> {
+		Self::Header::decode(input)
+	}
 }
 
 /// Something that acts like an `Extrinsic`.
@@ -1455,6 +1463,12 @@ pub trait SignedExtension:
 	/// in an extrinsic.
 	const IDENTIFIER: &'static str;
 
+	/// The number of individual signed extensions this one is composed of.
+	///
+	/// This is `1` for a single extension and the sum over all elements for a tuple. It matches
+	/// the number of entries returned by [`Self::metadata`].
+	const PIPELINE_LENGTH: usize = 1;
+
 	/// The type which encodes the sender identity.
 	type AccountId;
 
@@ -1564,7 +1578,7 @@ pub trait SignedExtension:
 	///
 	/// As a [`SignedExtension`] can be a tuple of [`SignedExtension`]s we need to return a `Vec`
 	/// that holds the metadata of each one. Each individual `SignedExtension` must return
-	/// *exactly* one [`SignedExtensionMetadata`].
+	/// *exactly* one [`SignedExtensionMetadata`], except for `()` which contributes nothing.
 	///
 	/// This method provides a default implementation that returns a vec containing a single
 	/// [`SignedExtensionMetadata`].
@@ -1587,12 +1601,15 @@ pub struct SignedExtensionMetadata {
 	pub additional_signed: MetaType,
 }
 
+// The upper bound is the largest tuple `core` implements `Debug` and `Eq` for, which every
+// `SignedExtension` requires. Longer pipelines can be built by nesting tuples.
 #[impl_for_tuples(1, 12)]
 impl<AccountId, Call: Dispatchable> SignedExtension for Tuple {
 	for_tuples!( where #( Tuple: SignedExtension<AccountId=AccountId, Call=Call,> )* );
 	type AccountId = AccountId;
 	type Call = Call;
 	const IDENTIFIER: &'static str = "You should call `identifier()`!";
+	const PIPELINE_LENGTH: usize = for_tuples!( #( Tuple::PIPELINE_LENGTH )+* );
 	for_tuples!( type AdditionalSigned = ( #( Tuple::AdditionalSigned ),* ); );
 	for_tuples!( type Pre = ( #( Tuple::Pre ),* ); );
 
@@ -1666,6 +1683,129 @@ impl<AccountId, Call: Dispatchable> SignedExtension for Tuple {
 	}
 }
 
+/// Prefix marking the [`SignedExtension::IDENTIFIER`] of an optional extension.
+const OPTIONAL_IDENTIFIER_PREFIX: &str = "Optional";
+
+/// Longest identifier of an optional extension, prefix included.
+const MAX_OPTIONAL_IDENTIFIER_LEN: usize = 128;
+
+/// Builds the identifier of `Option<T>` at compile time.
+struct OptionalIdentifier<T>(PhantomData<T>);
+
+impl<T: SignedExtension> OptionalIdentifier<T> {
+	const LEN: usize = OPTIONAL_IDENTIFIER_PREFIX.len() + T::IDENTIFIER.len();
+
+	const BYTES: &'static [u8; MAX_OPTIONAL_IDENTIFIER_LEN] = &{
+		assert!(Self::LEN <= MAX_OPTIONAL_IDENTIFIER_LEN, "SignedExtension identifier is too long");
+		let mut bytes = [0u8; MAX_OPTIONAL_IDENTIFIER_LEN];
+		let (prefix, identifier) =
+			(OPTIONAL_IDENTIFIER_PREFIX.as_bytes(), T::IDENTIFIER.as_bytes());
+		let mut i = 0;
+		while i < prefix.len() {
+			bytes[i] = prefix[i];
+			i += 1;
+		}
+		let mut j = 0;
+		while j < identifier.len() {
+			bytes[i + j] = identifier[j];
+			j += 1;
+		}
+		bytes
+	};
+
+	const VALUE: &'static str = {
+		let mut bytes: &[u8] = Self::BYTES;
+		while bytes.len() > Self::LEN {
+			if let [rest @ .., _] = bytes {
+				bytes = rest;
+			}
+		}
+		match core::str::from_utf8(bytes) {
+			Ok(identifier) => identifier,
+			Err(_) => panic!("concatenation of two `str`s is valid UTF-8; qed"),
+		}
+	};
+}
+
+/// An optional [`SignedExtension`]. `None` accepts every transaction and does nothing.
+///
+/// # Warning
+///
+/// Whether the extension is present is decoded from the transaction, so it is chosen by the
+/// signer and not by the runtime. Any signer can send `None` and skip the wrapped extension
+/// entirely, including its additional signed data. **Never** wrap an extension the runtime relies
+/// on for validity, replay protection or fees, such as `CheckNonce`, `CheckMortality` or
+/// `ChargeTransactionPayment`. Only wrap extensions a signer may legitimately opt out of.
+///
+/// The identifier is `T::IDENTIFIER` prefixed with `Optional`, so metadata tools can tell the
+/// extension is optional. `Option<T>` is not codec compatible with `T`: both the extension and its
+/// additional signed data are prefixed with a byte telling whether the extension is present.
+impl<T: SignedExtension> SignedExtension for Option<T> {
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = Option<T::AdditionalSigned>;
+	type Pre = Option<T::Pre>;
+	const IDENTIFIER: &'static str = OptionalIdentifier::<T>::VALUE;
+	const PIPELINE_LENGTH: usize = T::PIPELINE_LENGTH;
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		self.as_ref().map(|ext| ext.additional_signed()).transpose()
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		match self {
+			Some(ext) => ext.validate(who, call, info, len),
+			None => Ok(ValidTransaction::default()),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		self.map(|ext| ext.pre_dispatch(who, call, info, len)).transpose()
+	}
+
+	fn validate_unsigned(
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		T::validate_unsigned(call, info, len)
+	}
+
+	fn pre_dispatch_unsigned(
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<(), TransactionValidityError> {
+		T::pre_dispatch_unsigned(call, info, len)
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		match pre {
+			// The extension was not part of the transaction.
+			Some(None) => Ok(()),
+			pre => T::post_dispatch(pre.flatten(), info, post_info, len, result),
+		}
+	}
+}
+
 /// Only for bare bone testing when you don't care about signed extensions at all.
 #[cfg(feature = "std")]
 impl SignedExtension for () {
@@ -1673,7 +1813,8 @@ impl SignedExtension for () {
 	type AdditionalSigned = ();
 	type Call = ();
 	type Pre = ();
-	const IDENTIFIER: &'static str = "UnitSignedExtension";
+	const IDENTIFIER: &'static str = "";
+	const PIPELINE_LENGTH: usize = 0;
 	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
 		Ok(())
 	}
@@ -1686,6 +1827,10 @@ impl SignedExtension for () {
 	) -> Result<Self::Pre, TransactionValidityError> {
 		self.validate(who, call, info, len).map(|_| ())
 	}
+	/// `()` encodes to nothing, so it is left out of the metadata.
+	fn metadata() -> Vec<SignedExtensionMetadata> {
+		Vec::new()
+	}
 }
 
 /// An "executable" piece of information, used by the standard Substrate Executive in order to
@@ -252,6 +252,7 @@ pub enum TransactionSource {
 
 /// Information concerning a valid transaction.
 #[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValidTransaction {
 	/// Priority of the transaction.
 	///
@@ -262,6 +263,7 @@ pub struct ValidTransaction {
 	///
 	/// A non-empty list signifies that some other transactions which provide
 	/// given tags are required to be included before that one.
+	#[cfg_attr(feature = "serde", serde(with = "hex_tags"))]
 	pub requires: Vec<TransactionTag>,
 	/// Provided tags
 	///
@@ -269,6 +271,7 @@ pub struct ValidTransaction {
 	/// will enable other transactions that depend on (require) those tags to be included as well.
 	/// Provided and required tags allow Substrate to build a dependency graph of transactions
 	/// and import them in the right (linear) order.
+	#[cfg_attr(feature = "serde", serde(with = "hex_tags"))]
 	pub provides: Vec<TransactionTag>,
 	/// Transaction longevity
 	///
@@ -283,6 +286,23 @@ pub struct ValidTransaction {
 	pub propagate: bool,
 }
 
+/// (De)serialize a list of `TransactionTag`s as hex strings.
+#[cfg(feature = "serde")]
+mod hex_tags {
+	use super::TransactionTag;
+	use serde::{Deserialize, Deserializer, Serializer};
+	use sp_std::prelude::*;
+
+	pub fn serialize<S: Serializer>(tags: &[TransactionTag], s: S) -> Result<S::Ok, S::Error> {
+		s.collect_seq(tags.iter().map(|tag| sp_core::bytes::to_hex(tag, false)))
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<TransactionTag>, D::Error> {
+		Vec::<sp_core::Bytes>::deserialize(d)
+			.map(|tags| tags.into_iter().map(|tag| tag.0).collect())
+	}
+}
+
 impl Default for ValidTransaction {
 	fn default() -> Self {
 		Self {
@@ -296,6 +316,47 @@ impl Default for ValidTransaction {
 }
 
 impl ValidTransaction {
+	/// Create a new `ValidTransaction` with the default values.
+	///
+	/// The remaining methods taking `self` can be chained to set the individual fields.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Set the priority of the transaction.
+	pub fn priority(mut self, priority: TransactionPriority) -> Self {
+		self.priority = priority;
+		self
+	}
+
+	/// Set the longevity of the transaction.
+	pub fn longevity(mut self, longevity: TransactionLongevity) -> Self {
+		self.longevity = longevity;
+		self
+	}
+
+	/// Set the propagate flag of the transaction.
+	pub fn propagate(mut self, propagate: bool) -> Self {
+		self.propagate = propagate;
+		self
+	}
+
+	/// Add a raw `TransactionTag` to the set of provided tags.
+	///
+	/// Unlike [`ValidTransactionBuilder::and_provides`] the tag is neither encoded nor prefixed.
+	pub fn provides(mut self, tag: TransactionTag) -> Self {
+		self.provides.push(tag);
+		self
+	}
+
+	/// Add a raw `TransactionTag` to the set of required tags.
+	///
+	/// Unlike [`ValidTransactionBuilder::and_requires`] the tag is neither encoded nor prefixed.
+	pub fn requires(mut self, tag: TransactionTag) -> Self {
+		self.requires.push(tag);
+		self
+	}
+
 	/// Initiate `ValidTransaction` builder object with a particular prefix for tags.
 	///
 	/// To avoid conflicts between different parts in runtime it's recommended to build `requires`
@@ -305,18 +366,18 @@ impl ValidTransaction {
 	}
 
 	/// Combine two instances into one, as a best effort. This will take the superset of each of the
-	/// `provides` and `requires` tags, it will sum the priorities, take the minimum longevity and
-	/// the logic *And* of the propagate flags.
+	/// `provides` and `requires` tags (keeping a single copy of tags present in both), it will sum
+	/// the priorities, take the minimum longevity and the logic *And* of the propagate flags.
 	pub fn combine_with(mut self, mut other: ValidTransaction) -> Self {
 		Self {
 			priority: self.priority.saturating_add(other.priority),
 			requires: {
 				self.requires.append(&mut other.requires);
-				self.requires
+				dedup_tags(self.requires)
 			},
 			provides: {
 				self.provides.append(&mut other.provides);
-				self.provides
+				dedup_tags(self.provides)
 			},
 			longevity: self.longevity.min(other.longevity),
 			propagate: self.propagate && other.propagate,
@@ -324,6 +385,13 @@ impl ValidTransaction {
 	}
 }
 
+/// Remove the duplicates from `tags`, keeping the first occurrence of each tag.
+fn dedup_tags(mut tags: Vec<TransactionTag>) -> Vec<TransactionTag> {
+	let mut seen = sp_std::collections::btree_set::BTreeSet::new();
+	tags.retain(|tag| seen.insert(tag.clone()));
+	tags
+}
+
 /// `ValidTransaction` builder.
 ///
 ///
@@ -448,6 +516,18 @@ mod tests {
 		assert_eq!(TransactionValidity::decode(&mut &*encoded), Ok(v));
 	}
 
+	#[test]
+	fn should_serialize_tags_as_hex() {
+		let v = ValidTransaction::new().priority(5).requires(vec![1, 2]).provides(vec![0xab]);
+
+		let json = serde_json::to_string(&v).unwrap();
+		assert_eq!(
+			json,
+			r#"{"priority":5,"requires":["0x0102"],"provides":["0xab"],"longevity":18446744073709551615,"propagate":true}"#
+		);
+		assert_eq!(serde_json::from_str::<ValidTransaction>(&json).unwrap(), v);
+	}
+
 	#[test]
 	fn builder_should_prefix_the_tags() {
 		const PREFIX: &str = "test";
@@ -472,4 +552,53 @@ mod tests {
 			}
 		);
 	}
+
+	#[test]
+	fn builder_methods_should_set_the_fields() {
+		let a = ValidTransaction::new()
+			.priority(100)
+			.longevity(128)
+			.propagate(false)
+			.provides(vec![1])
+			.provides(vec![2])
+			.requires(vec![3]);
+		assert_eq!(
+			a,
+			ValidTransaction {
+				priority: 100,
+				requires: vec![vec![3]],
+				provides: vec![vec![1], vec![2]],
+				longevity: 128,
+				propagate: false,
+			}
+		);
+	}
+
+	#[test]
+	fn combine_with_should_merge_the_tags_without_duplicates() {
+		let a = ValidTransaction {
+			priority: 1,
+			requires: vec![vec![1], vec![2]],
+			provides: vec![vec![3]],
+			longevity: 10,
+			propagate: true,
+		};
+		let b = ValidTransaction {
+			priority: 2,
+			requires: vec![vec![2], vec![4]],
+			provides: vec![vec![5], vec![3]],
+			longevity: 5,
+			propagate: false,
+		};
+		assert_eq!(
+			a.combine_with(b),
+			ValidTransaction {
+				priority: 3,
+				requires: vec![vec![1], vec![2], vec![4]],
+				provides: vec![vec![3], vec![5]],
+				longevity: 5,
+				propagate: false,
+			}
+		);
+	}
 }